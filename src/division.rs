@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use synth::{SF, Synth};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Division {
+    #[default]
+    Manual,
+    Control,
+    Pedal,
+}
+
+impl Division {
+    pub const ALL: [Division; 3] = [Division::Manual, Division::Control, Division::Pedal];
+}
+
+// Every division plays its own synth, with its own voices, timbre, tuning slot and volume,
+// and they are mixed into one output like the divisions of an organ. The timbre presets are
+// one bank that each division picks from, and dynamic tuning moves one frame for them all
+pub struct Divisions {
+    synths: [Synth; 3],
+    // Receives the actions not bound to a division
+    selected: Division,
+}
+
+impl Divisions {
+    pub fn new(synth: Synth) -> Self {
+        Self {
+            synths: [synth.clone(), synth.clone(), synth],
+            selected: Division::Manual,
+        }
+    }
+
+    pub fn get(&self, division: Division) -> &Synth {
+        &self.synths[division as usize]
+    }
+
+    pub fn get_mut(&mut self, division: Division) -> &mut Synth {
+        &mut self.synths[division as usize]
+    }
+
+    pub fn selected(&self) -> Division {
+        self.selected
+    }
+
+    pub fn select(&mut self, division: Division) {
+        println!("Division: {division:?}");
+        self.selected = division;
+    }
+
+    // Copies the preset a division just edited into the others' banks, so that saving from any
+    // division keeps every edit and divisions playing the same preset sound it too
+    pub fn share_timbre_preset(&mut self, from: Division) {
+        let index = self.get(from).timbre_index();
+        let preset = self.get(from).timbre_presets[index];
+
+        for (division, synth) in Division::ALL.into_iter().zip(&mut self.synths) {
+            if division == from {
+                continue;
+            }
+
+            synth.timbre_presets[index] = preset;
+
+            if synth.timbre_index() == index {
+                synth.change_timbre_bank(index);
+            }
+        }
+    }
+
+    // Lets a division hear what the others hold before it plays, so that adapting fits all of
+    // their notes and anchoring on silence waits for all of them
+    pub fn accompany(&mut self, division: Division) {
+        let notes = Division::ALL
+            .into_iter()
+            .filter(|&other| other != division)
+            .flat_map(|other| self.get(other).held_notes())
+            .collect();
+
+        self.get_mut(division).set_accompaniment(notes);
+    }
+
+    // Moves the others along with the dynamic frame of a division that just played or retuned
+    pub fn share_frame(&mut self, from: Division) {
+        let frame = self.get(from).frame();
+
+        for synth in &mut self.synths {
+            synth.set_frame(frame);
+        }
+    }
+
+    // Carries tuning actions performed on one division over to the others
+    pub fn share_tuning(&mut self, from: Division) {
+        let state = self.get(from).tuning_state();
+
+        self.share_frame(from);
+
+        for synth in &mut self.synths {
+            synth.set_tuning_state(state);
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Synth> {
+        self.synths.iter_mut()
+    }
+}
+
+impl Iterator for Divisions {
+    type Item = SF;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sum = self
+            .synths
+            .iter_mut()
+            .filter_map(Synth::next)
+            .fold(0 as SF, SF::saturating_add);
+
+        Some(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synth::SynthSetting;
+
+    // As the settings file stores it
+    fn attack(synth: &Synth, preset: usize) -> serde_json::Value {
+        serde_json::to_value(synth.timbre_presets[preset]).unwrap()["oscillator_attack"].clone()
+    }
+
+    #[test]
+    fn timbre_edits_reach_every_division() {
+        let mut divisions = Divisions::new(Synth::new(
+            [SynthSetting::default(); 8],
+            Vec::new(),
+            440.0,
+            69,
+        ));
+
+        divisions.get_mut(Division::Control).change_timbre_bank(1);
+        divisions.get_mut(Division::Manual).set_attack(5);
+        divisions.share_timbre_preset(Division::Manual);
+        divisions.get_mut(Division::Control).set_attack(9);
+        divisions.share_timbre_preset(Division::Control);
+
+        for division in Division::ALL {
+            let synth = divisions.get(division);
+
+            assert_eq!(attack(synth, 0), 5);
+            assert_eq!(attack(synth, 1), 9);
+        }
+
+        assert_eq!(divisions.get(Division::Pedal).timbre_index(), 0);
+        assert_eq!(divisions.get(Division::Control).timbre_index(), 1);
+    }
+}
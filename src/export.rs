@@ -0,0 +1,63 @@
+use anyhow::{Context, Result, anyhow};
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::mts;
+
+// Writes `<prefix>.scl`, `<prefix>.kbm` and `<prefix>.syx`. Every tuned key becomes a scale
+// degree above the lowest one so that arbitrary frequency maps survive the round trip
+pub fn export_tuning(prefix: &str, frequencies: &[f64; 128]) -> Result<()> {
+    let keys: Vec<usize> = (0..128).filter(|&key| frequencies[key] > 0.0).collect();
+
+    let [first, .., last] = keys[..] else {
+        return Err(anyhow!("Fewer than two keys are tuned, nothing to export"));
+    };
+
+    let name = Path::new(prefix)
+        .file_name()
+        .map_or(prefix.into(), |name| name.to_string_lossy());
+
+    let mut scale = format!("! {name}.scl\n!\nExported tuning\n {}\n!\n", keys.len() - 1);
+
+    for &key in &keys[1..] {
+        let cents = (frequencies[key] / frequencies[first]).log2() * 1200.0;
+
+        writeln!(scale, " {cents:.6}")?;
+    }
+
+    let mut mapping = format!(
+        "! {name}.kbm\n!\n\
+         ! Map size:\n{}\n\
+         ! First MIDI note number to retune:\n{first}\n\
+         ! Last MIDI note number to retune:\n{last}\n\
+         ! Middle note where the first entry of the mapping is mapped to:\n{first}\n\
+         ! Reference note for which frequency is given:\n{first}\n\
+         ! Frequency to tune the above note to:\n{:.6}\n\
+         ! Scale degree to consider as formal octave:\n{}\n\
+         ! Mapping.\n",
+        last - first,
+        frequencies[first],
+        keys.len() - 1,
+    );
+
+    for key in first..last {
+        match keys.iter().position(|&k| k == key) {
+            Some(degree) => writeln!(mapping, "{degree}")?,
+            None => writeln!(mapping, "x")?,
+        }
+    }
+
+    for (extension, data) in [
+        ("scl", scale.into_bytes()),
+        ("kbm", mapping.into_bytes()),
+        ("syx", mts::bulk_dump(&name, 0, frequencies)),
+    ] {
+        let filename = format!("{prefix}.{extension}");
+
+        std::fs::write(&filename, data).with_context(|| format!("Can't write {filename}"))?;
+    }
+
+    println!("Exported tuning to {prefix}.scl, .kbm and .syx");
+
+    Ok(())
+}
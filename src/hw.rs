@@ -1,28 +1,126 @@
-use alsa::Direction;
-use alsa::PollDescriptors;
-use alsa::direct::pcm::MmapPlayback;
-use alsa::poll::poll;
-// #[cfg(not(test))]
-use alsa::seq::Event;
+use alsa::poll::{poll, pollfd};
 use anyhow::Result;
 
-use crate::Synth;
-use crate::midi::MidiInputStream;
+use crate::midi::{MidiEvent, MidiInputStream};
 use crate::pcm::OutputDevice;
 
 pub const SAMPLE_RATE: u32 = synth::SAMPLE_RATE;
+pub const CHANNELS: u16 = 2;
 
 pub type SF = synth::SF;
 
-pub struct IO {
-    input_stream: MidiInputStream,
-    output_device: OutputDevice,
-    mmap: MmapPlayback<SF>,
-    fds: Vec<alsa::poll::pollfd>,
+pub trait AudioSink {
+    // Frames written so far, which is the clock events are scheduled against
+    fn position(&self) -> u64;
+
+    // Writes at most `frames` frames and returns how many were actually written
+    fn write(&mut self, mixer: &mut impl Iterator<Item = SF>, frames: u64) -> Result<u64>;
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn descriptors(&self) -> Result<Vec<pollfd>> {
+        Ok(Vec::new())
+    }
+}
+
+pub trait EventSource {
+    // Returns the next event due at or before `position`
+    fn read(&mut self, position: u64) -> Result<Option<MidiEvent>>;
+
+    // Position of the next scheduled event, live sources have none
+    fn next_event(&self) -> Option<u64> {
+        None
+    }
+
+    fn is_finished(&self, _position: u64) -> bool {
+        false
+    }
+
+    fn descriptors(&self) -> Result<Vec<pollfd>> {
+        Ok(Vec::new())
+    }
+}
+
+pub struct NullSink {
+    position: u64,
+}
+
+impl NullSink {
+    const BLOCK_SIZE: u64 = 1024;
+
+    pub fn new() -> Self {
+        Self { position: 0 }
+    }
+}
+
+impl Default for NullSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioSink for NullSink {
+    fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn write(&mut self, mixer: &mut impl Iterator<Item = SF>, frames: u64) -> Result<u64> {
+        let frames = frames.min(Self::BLOCK_SIZE);
+
+        mixer
+            .by_ref()
+            .take(frames as usize * CHANNELS as usize)
+            .for_each(drop);
+
+        self.position += frames;
+
+        Ok(frames)
+    }
 }
 
-impl IO {
-    pub fn new(
+#[derive(Default)]
+pub struct MemorySink {
+    samples: Vec<SF>,
+}
+
+impl MemorySink {
+    const BLOCK_SIZE: u64 = 1024;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Interleaved, CHANNELS samples per frame
+    pub fn samples(&self) -> &[SF] {
+        &self.samples
+    }
+}
+
+impl AudioSink for MemorySink {
+    fn position(&self) -> u64 {
+        (self.samples.len() / CHANNELS as usize) as u64
+    }
+
+    fn write(&mut self, mixer: &mut impl Iterator<Item = SF>, frames: u64) -> Result<u64> {
+        let frames = frames.min(Self::BLOCK_SIZE);
+
+        self.samples
+            .extend(mixer.by_ref().take(frames as usize * CHANNELS as usize));
+
+        Ok(frames)
+    }
+}
+
+pub struct IO<E: EventSource, A: AudioSink> {
+    source: E,
+    sink: A,
+    fds: Vec<pollfd>,
+}
+
+impl IO<MidiInputStream, OutputDevice> {
+    pub fn alsa(
         main_port: i32,
         aux_port: i32,
         expr_port: i32,
@@ -34,27 +132,52 @@ impl IO {
             MidiInputStream::new(main_port, aux_port, expr_port, mixer_port, pedal_port)?;
         let output_device = OutputDevice::new(card)?;
 
-        let mut fds = output_device.get()?;
+        Self::new(input_stream, output_device)
+    }
+}
+
+impl<E: EventSource, A: AudioSink> IO<E, A> {
+    pub fn new(source: E, sink: A) -> Result<Self> {
+        let mut fds = sink.descriptors()?;
+
+        fds.append(&mut source.descriptors()?);
+
+        Ok(Self { source, sink, fds })
+    }
 
-        fds.append(&mut (input_stream.device(), Some(Direction::Capture)).get()?);
+    pub fn sink(&self) -> &A {
+        &self.sink
+    }
 
-        Ok(Self {
-            input_stream,
-            mmap: output_device.device().direct_mmap_playback::<SF>()?,
-            fds,
-            output_device,
-        })
+    pub fn is_finished(&self) -> bool {
+        self.source.is_finished(self.sink.position())
     }
 
+    // Sources without file descriptors never block, there is nothing to wait for
     pub fn poll(&mut self) -> Result<usize> {
+        if self.fds.is_empty() {
+            return Ok(0);
+        }
+
         Ok(poll(&mut self.fds, -1)?)
     }
 
-    pub fn read(&mut self) -> Result<Option<Event>> {
-        MidiInputStream::read_midi_event(self.input_stream.input())
+    pub fn read(&mut self) -> Result<Option<MidiEvent>> {
+        self.source.read(self.sink.position())
+    }
+
+    pub fn write(&mut self, mixer: &mut impl Iterator<Item = SF>) -> Result<()> {
+        let frames = self
+            .source
+            .next_event()
+            .map_or(u64::MAX, |next| next.saturating_sub(self.sink.position()));
+
+        self.sink.write(mixer, frames)?;
+
+        Ok(())
     }
 
-    pub fn write(&mut self, synth: &mut Synth) -> Result<()> {
-        OutputDevice::write_samples_direct(self.output_device.device(), &mut self.mmap, synth)
+    pub fn finish(&mut self) -> Result<()> {
+        self.sink.finish()
     }
 }
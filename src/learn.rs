@@ -0,0 +1,107 @@
+use synth::Mode;
+
+use crate::mapping::{Action, Binding, Mapping};
+
+pub enum Outcome {
+    Ignored,
+    Consumed,
+    Learned(Binding),
+}
+
+// Press learn, move the knob to bind, then either move a control that already does the
+// job or scroll to the target with the selector and press learn again
+#[derive(Default)]
+pub enum Learn {
+    #[default]
+    Idle,
+    Armed,
+    Captured {
+        channel: u8,
+        param: u32,
+        target: Option<Action>,
+    },
+}
+
+impl Learn {
+    pub fn press(&mut self) -> Option<Binding> {
+        match std::mem::take(self) {
+            Learn::Idle => {
+                println!("Learning: move a control");
+                *self = Learn::Armed;
+
+                None
+            }
+            Learn::Captured {
+                channel,
+                param,
+                target: Some(action),
+            } => Some(Binding::controller(
+                channel,
+                param,
+                action,
+                action.default_range(),
+            )),
+            Learn::Armed | Learn::Captured { target: None, .. } => {
+                println!("Learning cancelled");
+
+                None
+            }
+        }
+    }
+
+    pub fn controller(
+        &mut self,
+        mapping: &Mapping,
+        channel: u8,
+        param: u32,
+        value: i32,
+        mode: Mode,
+    ) -> Outcome {
+        match self {
+            Learn::Idle => Outcome::Ignored,
+            Learn::Armed => {
+                println!("Learning controller {param} on channel {channel}: pick a target");
+                *self = Learn::Captured {
+                    channel,
+                    param,
+                    target: None,
+                };
+
+                Outcome::Consumed
+            }
+            Learn::Captured {
+                channel: learned_channel,
+                param: learned_param,
+                target,
+            } => {
+                if (*learned_channel, *learned_param) == (channel, param) {
+                    return Outcome::Consumed;
+                }
+
+                match mapping.find_controller(channel, param, mode) {
+                    Some(binding) if binding.action == Action::LearnSelect => {
+                        let index = value as usize * Action::LEARNABLE.len() / 128;
+                        let action = Action::LEARNABLE[index.min(Action::LEARNABLE.len() - 1)];
+
+                        println!("Learning target: {}", action.name());
+                        target.replace(action);
+
+                        Outcome::Consumed
+                    }
+                    Some(binding) => {
+                        let learned = Binding::controller(
+                            *learned_channel,
+                            *learned_param,
+                            binding.action,
+                            binding.range,
+                        );
+                        *self = Learn::Idle;
+
+                        Outcome::Learned(learned)
+                    }
+                    None => Outcome::Consumed,
+                }
+            }
+        }
+    }
+}
@@ -1,35 +1,36 @@
-use crate::hw::IO;
-use ::alsa::seq::EventType;
-use alsa::seq::{EvCtrl, EvNote};
-use anyhow::Result;
+use crate::division::{Division, Divisions};
+use crate::hw::{AudioSink, EventSource, IO, NullSink};
+use crate::learn::{Learn, Outcome};
+use crate::mapping::{
+    Action, Binding, Mapping, bend_mode, pressure_target, velocity_curve, waveform,
+};
+use crate::midi::{MidiEvent, MpeZone, RpnTracker};
+use crate::render::{SmfSource, WavSink};
+use crate::scala::{KeyboardMapping, Tuning};
+use crate::watch::{Change, Reloader};
+use anyhow::{Context, Result, anyhow};
 use bpaf::Bpaf;
 use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap};
 use std::fs::File;
-use std::io::{BufReader, Read};
-use std::path::Path;
-use synth::oscillator::Waveform;
-use synth::{Mode, Synth, SynthSetting};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use synth::{Anchor, DynamicTable, Mode, Synth, SynthSetting};
 
+mod division;
+mod export;
 pub mod hw;
+mod learn;
+mod mapping;
 mod midi;
+mod mts;
 mod pcm;
+mod render;
 mod scala;
+mod watch;
 
-#[derive(Bpaf)]
-#[bpaf(options)]
-pub struct Options {
-    #[bpaf(short('p'), long, argument)]
-    pub main_port: i32,
-    #[bpaf(short('a'), long, argument)]
-    pub aux_port: i32,
-    #[bpaf(short('e'), long, argument)]
-    pub expr_port: i32,
-    #[bpaf(short('m'), long, argument)]
-    pub mixer_port: i32,
-    #[bpaf(short('f'), long, argument)]
-    pub pedal_port: i32,
-    #[bpaf(short('c'), long, argument)]
-    pub card: String,
+#[derive(Bpaf, Default)]
+pub struct SynthOptions {
     #[bpaf(short('s'), long, argument)]
     pub settings_filename: Option<String>,
     #[bpaf(short('t'), long, argument)]
@@ -38,96 +39,96 @@ pub struct Options {
     pub base_frequency: Option<f64>,
     #[bpaf(short('n'), long, argument)]
     pub base_note: Option<u8>,
+    /// JSON file binding MIDI notes and controllers to actions
+    #[bpaf(short('k'), long("mapping"), argument)]
+    pub mapping_filename: Option<String>,
+    /// Treat this many channels from channel 2 up as an MPE lower zone, one note per channel
+    #[bpaf(long, argument("CHANNELS"))]
+    pub mpe: Option<u8>,
+    /// Pick the fundamental and prime limit from the held notes in dynamic mode
+    #[bpaf(long)]
+    pub adaptive: bool,
+    /// Tune adaptive fundamentals from the base frequency instead of the held notes
+    #[bpaf(long)]
+    pub compensate_drift: bool,
+    /// How the dynamic fundamental returns to the base frequency: free, silence, recenter or
+    /// the most cents it may drift
+    #[bpaf(long, argument("STRATEGY"))]
+    pub anchor: Option<Anchor>,
+    /// 12-note .scl or JSON interval table for dynamic mode, may be repeated
+    #[bpaf(long("dynamic-table"), argument("FILE"), many)]
+    pub dynamic_tables: Vec<String>,
 }
 
-const C0: u8 = 12;
-const H0: u8 = 23;
-const C1: u8 = 24;
-const CIS1: u8 = 25;
-const D1: u8 = 26;
-const H1: u8 = 35;
-const C2: u8 = 36;
-const C3: u8 = 48;
-const H3: u8 = 59;
-const C4: u8 = 60;
-const C5: u8 = 72;
-const CIS5: u8 = 73;
-const C6: u8 = 84;
-const MIXER: u8 = 0;
-const MANUAL: u8 = 1;
-const CONTROL: u8 = 2;
-const EXPRESSION: u8 = 3;
-const PEDALS: u8 = 4;
-const VOLUME: u32 = 21;
-const VIBRATO: u32 = 22;
-const DAMPER: u32 = 64;
-const OSCILLATOR1_WAVEFORM: u32 = 16;
-const OSCILLATOR2_WAVEFORM: u32 = 24;
-const OSCILLATOR1_DUTY: u32 = 20;
-const OSCILLATOR2_DUTY: u32 = 28;
-const ATTACK: u32 = 46;
-const DECAY: u32 = 50;
-const SUSTAIN: u32 = 54;
-const RELEASE: u32 = 58;
-const MODULATOR1_WAVEFORM: u32 = 17;
-const MODULATOR1_DUTY: u32 = 21;
-const MODULATOR1_RATIO: u32 = 25;
-const MODULATOR1_AMOUNT: u32 = 29;
-const MODULATOR1_ATTACK: u32 = 47;
-const MODULATOR1_DECAY: u32 = 51;
-const MODULATOR1_SUSTAIN: u32 = 55;
-const MODULATOR1_RELEASE: u32 = 59;
-const MODULATOR2_WAVEFORM: u32 = 18;
-const MODULATOR2_DUTY: u32 = 22;
-const MODULATOR2_RATIO: u32 = 26;
-const MODULATOR2_AMOUNT: u32 = 30;
-const MODULATOR2_ATTACK: u32 = 48;
-const MODULATOR2_DECAY: u32 = 52;
-const MODULATOR2_SUSTAIN: u32 = 56;
-const MODULATOR2_RELEASE: u32 = 60;
-const TIMBRE_BANK_1: u8 = 1;
-const TIMBRE_BANK_2: u8 = 4;
-const TIMBRE_BANK_3: u8 = 7;
-const TIMBRE_BANK_4: u8 = 10;
-const TIMBRE_BANK_5: u8 = 13;
-const TIMBRE_BANK_6: u8 = 16;
-const TIMBRE_BANK_7: u8 = 19;
-const TIMBRE_BANK_8: u8 = 22;
-const TUNING_BANK_1: u8 = 3;
-const TUNING_BANK_2: u8 = 6;
-const TUNING_BANK_3: u8 = 9;
-const TUNING_BANK_4: u8 = 12;
-const TUNING_BANK_5: u8 = 15;
-const TUNING_BANK_6: u8 = 18;
-const TUNING_BANK_7: u8 = 21;
-const TUNING_BANK_8: u8 = 24;
-const SAVE_TIMBRE_PRESETS: u8 = 27;
-const ENV_LENGTH: u32 = 19;
-const MOD1_ENV_LENGTH: u32 = 23;
-const MOD2_ENV_LENGTH: u32 = 27;
-const MOD1_RATIO_SPECTRUM: u32 = 31;
-const MOD1_AMOUNT_SPECTRUM: u32 = 49;
-const MOD2_RATIO_SPECTRUM: u32 = 53;
-const MOD2_AMOUNT_SPECTRUM: u32 = 57;
-const VIBRATO_DEPTH: u32 = 61;
-const OSCILLATOR_BALANCE: u32 = 62;
+#[derive(Bpaf)]
+#[bpaf(options)]
+pub enum Options {
+    /// Render a Standard MIDI File to a WAV file
+    #[bpaf(command)]
+    Render {
+        #[bpaf(short('i'), long, argument)]
+        input: String,
+        /// WAV file to write, audio is discarded when omitted
+        #[bpaf(short('o'), long, argument)]
+        output: Option<String>,
+        /// Write 32-bit float samples instead of 16-bit integers
+        #[bpaf(long)]
+        float: bool,
+        /// Seconds to keep rendering after the last event
+        #[bpaf(long, argument)]
+        tail: Option<f64>,
+        #[bpaf(external(synth_options))]
+        synth_options: SynthOptions,
+    },
+    /// Write the effective tuning as .scl, .kbm and MTS .syx files
+    #[bpaf(command)]
+    Export {
+        /// Path of the exported files without extension
+        #[bpaf(short('o'), long, argument)]
+        output: String,
+        /// Standard MIDI File to play through first, for tunings reached by performing
+        #[bpaf(short('i'), long, argument)]
+        input: Option<String>,
+        #[bpaf(external(synth_options))]
+        synth_options: SynthOptions,
+    },
+    Play {
+        #[bpaf(short('p'), long, argument)]
+        main_port: i32,
+        #[bpaf(short('a'), long, argument)]
+        aux_port: i32,
+        #[bpaf(short('e'), long, argument)]
+        expr_port: i32,
+        #[bpaf(short('m'), long, argument)]
+        mixer_port: i32,
+        #[bpaf(short('f'), long, argument)]
+        pedal_port: i32,
+        #[bpaf(short('c'), long, argument)]
+        card: String,
+        #[bpaf(external(synth_options))]
+        synth_options: SynthOptions,
+    },
+}
 
-fn parse_settings_file(settings_filename: &str) -> [SynthSetting; 8] {
-    let mut settings: [SynthSetting; 8] = [SynthSetting::default(); 8];
+fn read_settings_file(settings_filename: &str) -> Result<[SynthSetting; 8]> {
+    let data = std::fs::read(settings_filename)
+        .with_context(|| format!("Can't read settings {settings_filename}"))?;
 
-    if let Ok(mut settings_file) = File::open(settings_filename) {
-        let mut data = Vec::new();
+    serde_json::from_slice(&data).with_context(|| format!("Malformed settings {settings_filename}"))
+}
 
-        if settings_file.read_to_end(&mut data).is_ok() {
-            settings = serde_json::from_slice(&data)
-                .map_err(|_| eprintln!("WARNING: "))
-                .unwrap_or(settings);
-        } else {
-            eprintln!("WARNING: ");
-        }
+// A missing settings file is created on the first save
+fn parse_settings_file(settings_filename: &str) -> [SynthSetting; 8] {
+    let settings: [SynthSetting; 8] = [SynthSetting::default(); 8];
+
+    if !Path::new(settings_filename).exists() {
+        return settings;
     }
 
-    settings
+    read_settings_file(settings_filename).unwrap_or_else(|e| {
+        eprintln!("WARNING: {e:#}");
+        settings
+    })
 }
 
 fn write_settings_to_file(settings_filename: &str, settings: [SynthSetting; 8]) {
@@ -141,372 +142,985 @@ fn write_settings_to_file(settings_filename: &str, settings: [SynthSetting; 8])
     }
 }
 
+// Where timbre presets are saved when no settings file was given
+const DEFAULT_SETTINGS_FILENAME: &str = "test";
+
+fn exported_tuning_prefix(settings_filename: &str) -> String {
+    Path::new(settings_filename)
+        .with_extension("tuning")
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn learned_mapping_filename(settings_filename: &str) -> String {
+    Path::new(settings_filename)
+        .with_extension("mapping.json")
+        .to_string_lossy()
+        .into_owned()
+}
+
 fn parse_tuning_preset_file(
     tuning_preset_filename: &str,
+    keyboard_mapping_filename: Option<&str>,
     base_freq: f64,
     base_note: usize,
-) -> [f64; 128] {
-    let scale = scala::parse_scala_file(tuning_preset_filename);
-
-    dbg!(&scale);
+    mode: usize,
+) -> Result<Tuning> {
+    let scale = scala::parse_scala_file(tuning_preset_filename)?;
 
-    // let normalized_base_note = base_note % scale.size();
+    // The keyboard mapping carries its own reference note and frequency
+    let mapping = match keyboard_mapping_filename {
+        Some(filename) => scala::parse_keyboard_mapping_file(filename)?,
+        None => KeyboardMapping::linear(base_note as u8, base_freq),
+    };
 
-    // let frequencies = scale.frequencies(base_freq, normalized_base_note);
-    let frequencies = scala::scale_to_tuning(scale, base_freq, base_note as u8);
+    // Named after the file unless the manifest says otherwise
+    let name = Path::new(tuning_preset_filename)
+        .file_stem()
+        .map_or(tuning_preset_filename.into(), |stem| stem.to_string_lossy())
+        .into_owned();
 
-    dbg!(frequencies);
-
-    frequencies
+    Ok(Tuning {
+        name,
+        scale,
+        mapping,
+        mode,
+    })
 }
 
+// Without a keyboard mapping the scale starts on the base note, which falls back to the one
+// given on the command line. The mode is the degree of the scale that becomes its 1/1
 #[derive(Deserialize)]
 struct ManifestEntry {
-    base_freq: f64,
-    base_note: usize,
+    name: Option<String>,
+    base_freq: Option<f64>,
+    base_note: Option<usize>,
     tuning_preset_filename: String,
+    keyboard_mapping_filename: Option<String>,
+    #[serde(default)]
+    mode: usize,
 }
 
-fn parse_tuning_directory(tuning_preset_directory: &str) -> [[f64; 128]; 24] {
-    let mut tunings = [[0.0; 128]; 24];
+fn parse_tuning_directory(
+    tuning_preset_directory: &str,
+    base_freq: f64,
+    base_note: usize,
+) -> Result<Vec<Tuning>> {
+    let mut tunings = Vec::new();
 
     let manifest_path = Path::new(tuning_preset_directory).join("manifest");
-    if let Ok(manifest_file) = File::open(manifest_path) {
-        let manifest: Vec<ManifestEntry> =
-            serde_json::from_reader(BufReader::new(manifest_file)).unwrap();
-
-        for (i, tuning) in manifest.iter().enumerate() {
-            let path = Path::new(tuning_preset_directory);
-            let path = path.join(&tuning.tuning_preset_filename);
-            let frequencies = parse_tuning_preset_file(
-                path.to_str().unwrap(),
-                tuning.base_freq,
-                tuning.base_note,
-            );
+    let manifest_file = File::open(&manifest_path)
+        .with_context(|| format!("Can't open manifest {}", manifest_path.display()))?;
+    let manifest: Vec<ManifestEntry> = serde_json::from_reader(BufReader::new(manifest_file))
+        .with_context(|| format!("Malformed manifest {}", manifest_path.display()))?;
+
+    // The Scala parsers name the files in their errors
+    let filename = |path: PathBuf| {
+        path.into_os_string()
+            .into_string()
+            .map_err(|path| anyhow!("Unreadable file name {}", path.display()))
+    };
+
+    for tuning in &manifest {
+        let path = Path::new(tuning_preset_directory);
+        let keyboard_mapping_filename = tuning
+            .keyboard_mapping_filename
+            .as_ref()
+            .map(|name| filename(path.join(name)))
+            .transpose()?;
+        let tuning_preset_filename = filename(path.join(&tuning.tuning_preset_filename))?;
+        let mut preset = parse_tuning_preset_file(
+            &tuning_preset_filename,
+            keyboard_mapping_filename.as_deref(),
+            tuning.base_freq.unwrap_or(base_freq),
+            tuning.base_note.unwrap_or(base_note),
+            tuning.mode,
+        )?;
+
+        if let Some(name) = &tuning.name {
+            preset.name = name.clone();
+        }
+
+        tunings.push(preset);
+    }
+
+    Ok(tunings)
+}
+
+// Either a 12-note octave scale or a JSON array of 12 ratios, one for each semitone
+fn load_dynamic_table(filename: &str) -> Result<DynamicTable> {
+    let path = Path::new(filename);
+    let name = path
+        .file_stem()
+        .map_or(filename.into(), |stem| stem.to_string_lossy())
+        .into_owned();
 
-            tunings[i] = frequencies;
+    let intervals = if path.extension().is_some_and(|extension| extension == "scl") {
+        let scale = scala::parse_scala_file(filename)?;
+
+        if scale.size() != 12 || (scale.period().cents() - 1200.0).abs() > 1e-6 {
+            return Err(anyhow!(
+                "Dynamic table {filename} must have 12 notes to the octave"
+            ));
         }
+
+        std::array::from_fn(|degree| scale.degree(degree as isize).to_f64())
     } else {
-        panic!("WARNING: ");
+        let file =
+            File::open(filename).with_context(|| format!("Can't open dynamic table {filename}"))?;
+
+        serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("Malformed dynamic table {filename}"))?
+    };
+
+    check_dynamic_table(filename, &intervals)?;
+
+    Ok(DynamicTable { name, intervals })
+}
+
+// Anything else plays silent or NaN frequencies. Comparisons with NaN fail, so it is caught
+// along with infinities and ratios that don't rise
+fn check_dynamic_table(filename: &str, intervals: &[f64; 12]) -> Result<()> {
+    if intervals[0] != 1.0 {
+        return Err(anyhow!(
+            "Dynamic table {filename} must start at 1/1, not {}",
+            intervals[0]
+        ));
+    }
+
+    if let Some(semitone) =
+        (1..12).find(|&i| !(intervals[i] > intervals[i - 1] && intervals[i] < 2.0))
+    {
+        return Err(anyhow!(
+            "Dynamic table {filename} has {} on semitone {semitone}, ratios must rise from 1/1 \
+             and stay below 2/1",
+            intervals[semitone]
+        ));
     }
 
-    tunings
+    Ok(())
 }
 
-pub fn run(options: Options) -> Result<()> {
-    let main_port = options.main_port;
-    let aux_port = options.aux_port;
-    let expr_port = options.expr_port;
-    let mixer_port = options.mixer_port;
-    let pedal_port = options.pedal_port;
-    let card = options.card;
-    let settings_filename = options.settings_filename;
-    let tuning_preset_filename = options.tuning_preset_filename;
-
-    let base_freq = options.base_frequency.unwrap_or(440.0);
-    let base_note = options.base_note.unwrap_or(69);
-
-    // TODO ugly hacks
-    let (settings, settings_filename) = settings_filename.map_or(
-        ([SynthSetting::default(); 8], "test".to_string()),
-        |filename| (parse_settings_file(filename.as_str()), filename.clone()),
-    );
-    let tuning_preset = tuning_preset_filename.map_or(None, |filename| {
-        let path = Path::new(&filename);
-        if path.is_dir() {
-            Some(parse_tuning_directory(&filename))
-        } else if path.is_file() {
-            Some([parse_tuning_preset_file(filename.as_str(), base_freq, base_note as usize); 24])
-        } else {
-            panic!("WARNING: ");
+fn load_tunings(
+    tuning_preset_filename: &str,
+    base_freq: f64,
+    base_note: u8,
+) -> Result<Vec<Tuning>> {
+    let path = Path::new(tuning_preset_filename);
+
+    if path.is_dir() {
+        parse_tuning_directory(tuning_preset_filename, base_freq, base_note as usize)
+    } else if path.is_file() {
+        let tuning = parse_tuning_preset_file(
+            tuning_preset_filename,
+            None,
+            base_freq,
+            base_note as usize,
+            0,
+        )?;
+
+        Ok(vec![tuning])
+    } else {
+        Err(anyhow!(
+            "No tuning preset file or directory {tuning_preset_filename}"
+        ))
+    }
+}
+
+struct Instrument {
+    divisions: Divisions,
+    mapping: Mapping,
+    learn: Learn,
+    settings_filename: Option<String>,
+    // Only kept next to a settings file given on the command line
+    learned_mapping_filename: Option<String>,
+    shift: bool,
+    // The mode each held key was pressed in, so a mode switch doesn't change its release
+    pressed: HashMap<(u8, u8), Mode>,
+    rpn: RpnTracker,
+    mpe: MpeZone,
+    // The scales behind the tuning presets, for changing modes
+    tunings: Vec<Tuning>,
+    tuning_preset_filename: Option<String>,
+    base_freq: f64,
+    base_note: u8,
+    reloader: Option<Reloader>,
+}
+
+impl Instrument {
+    fn new(options: SynthOptions) -> Result<Self> {
+        let settings_filename = options.settings_filename;
+        let learned_mapping_filename = settings_filename.as_deref().map(learned_mapping_filename);
+        let tuning_preset_filename = options.tuning_preset_filename;
+
+        let base_freq = options.base_frequency.unwrap_or(440.0);
+        let base_note = options.base_note.unwrap_or(69);
+
+        let settings = settings_filename
+            .as_deref()
+            .map_or([SynthSetting::default(); 8], parse_settings_file);
+        let tunings = tuning_preset_filename
+            .as_deref()
+            .map(|filename| load_tunings(filename, base_freq, base_note))
+            .transpose()?;
+
+        let tunings = tunings.unwrap_or_default();
+        let tuning_presets = tunings.iter().map(Tuning::preset).collect();
+        let mut mapping = match options.mapping_filename {
+            Some(filename) => {
+                println!("Loading mapping {filename}");
+                Mapping::load(&filename)?
+            }
+            None => Mapping::default(),
+        };
+
+        // Learned bindings apply on top of whichever mapping was given
+        if let Some(filename) = &learned_mapping_filename
+            && Path::new(filename).is_file()
+        {
+            println!("Loading learned bindings {filename}");
+            mapping.load_learned(filename)?;
         }
-    });
-
-    let mut io = IO::new(
-        main_port, aux_port, expr_port, mixer_port, pedal_port, &card,
-    )?;
-    let mut synth = Synth::new(settings, tuning_preset, base_freq, base_note);
-    // let mut control = Synth::new();
-    // let mut pedals = Synth::new();
-
-    synth.change_timbre_bank(0);
-
-    let mut octave_pedal = false;
-
-    loop {
-        io.write(&mut synth)?;
-
-        if let Some(event) = io.read()? {
-            match event.get_type() {
-                EventType::Noteoff => {
-                    if let Some(EvNote { channel, note, .. }) = event.get_data() {
-                        match synth.mode {
-                            Mode::Fixed => match channel {
-                                // TODO
-                                // CONTROL => control.silence(note),
-                                // MANUAL => synth.silence(note),
-                                // PEDALS => pedals.silence(note),
-                                // _ => {}
-                                PEDALS if note == 24 => {
-                                    octave_pedal = false;
-                                }
-                                PEDALS => synth.silence(note - 12),
-                                _ => synth.silence(note),
-                            },
-                            Mode::Dynamic => {
-                                match channel {
-                                    CONTROL => match note {
-                                        // C3..=H3 => {
-                                        //     synth.change_fundamental(note);
-                                        // }
-                                        C4..=C5 => {
-                                            synth.change_tuning(note);
-                                        }
-                                        _ => (),
-                                    },
-                                    MANUAL => synth.silence(note),
-                                    PEDALS => match note {
-                                        // C1..=H1 => {
-                                        //     synth.change_fundamental(note);
-                                        // }
-                                        C1..=H1 => {
-                                            synth.change_tuning(note + 36);
-                                        }
-                                        C2..=C5 => {
-                                            synth.silence(note - 24);
-                                        }
-                                        _ => (),
-                                    },
-                                    // _ => unreachable!(),
-                                    _ => {}
-                                }
-                            }
-                        }
+
+        let mut synth = Synth::new(settings, tuning_presets, base_freq, base_note);
+
+        synth.change_timbre_bank(0);
+        synth.set_adaptive_tuning(options.adaptive);
+        synth.set_drift_compensation(options.compensate_drift);
+        synth.set_anchor(options.anchor.unwrap_or_default());
+
+        for filename in &options.dynamic_tables {
+            synth.add_dynamic_table(load_dynamic_table(filename)?);
+        }
+
+        let mut instrument = Self {
+            divisions: Divisions::new(synth),
+            mapping,
+            learn: Learn::Idle,
+            settings_filename,
+            learned_mapping_filename,
+            shift: false,
+            pressed: HashMap::new(),
+            rpn: RpnTracker::new(),
+            mpe: MpeZone::default(),
+            tunings,
+            tuning_preset_filename,
+            base_freq,
+            base_note,
+            reloader: None,
+        };
+
+        if let Some(members) = options.mpe {
+            instrument.set_mpe_zone(members);
+        }
+
+        Ok(instrument)
+    }
+
+    // Member channels bypass the mapping, so bindings on them stop working while the zone lasts
+    fn set_mpe_zone(&mut self, members: u8) {
+        self.mpe = MpeZone::new(members);
+        println!("MPE zone with {} member channels", self.mpe.members());
+
+        let taken: BTreeSet<u8> = self
+            .mapping
+            .channels()
+            .filter(|&channel| self.mpe.is_member(channel))
+            .collect();
+
+        if !taken.is_empty() {
+            eprintln!("WARNING: The MPE zone takes over the bindings on channels {taken:?}");
+        }
+    }
+
+    // Picks up edits to the settings and tunings while playing, including a settings file
+    // that the first save creates
+    fn watch(&mut self) {
+        self.reloader = Some(Reloader::spawn(
+            self.settings_filename.clone(),
+            self.tuning_preset_filename.clone(),
+            self.base_freq,
+            self.base_note,
+        ));
+    }
+
+    // Broken files are reported and the previous version stays in use
+    fn reload(&mut self) {
+        let Some(reloader) = &self.reloader else {
+            return;
+        };
+
+        for change in reloader.changes() {
+            match change {
+                Change::Settings(filename, Ok(settings)) => {
+                    println!("Reloading settings {filename}");
+
+                    for synth in self.divisions.iter_mut() {
+                        synth.timbre_presets = *settings;
+                        synth.change_timbre_bank(synth.timbre_index());
                     }
                 }
-                // TODO ugly repetition
-                EventType::Noteon => {
-                    if let Some(EvNote { channel, note, .. }) = event.get_data() {
-                        match synth.mode {
-                            Mode::Fixed => match channel {
-                                MIXER => match note {
-                                    CIS1 => {
-                                        synth.toggle_modulator1_env_repeat();
-                                    }
-                                    D1 => {
-                                        synth.toggle_modulator2_env_repeat();
-                                    }
-                                    TIMBRE_BANK_1 => synth.change_timbre_bank(0),
-                                    TIMBRE_BANK_2 => synth.change_timbre_bank(1),
-                                    TIMBRE_BANK_3 => synth.change_timbre_bank(2),
-                                    TIMBRE_BANK_4 => synth.change_timbre_bank(3),
-                                    TIMBRE_BANK_5 => synth.change_timbre_bank(4),
-                                    TIMBRE_BANK_6 => synth.change_timbre_bank(5),
-                                    TIMBRE_BANK_7 => synth.change_timbre_bank(6),
-                                    TIMBRE_BANK_8 => synth.change_timbre_bank(7),
-                                    TUNING_BANK_1 => synth.change_tuning_bank(0),
-                                    TUNING_BANK_2 => synth.change_tuning_bank(1),
-                                    TUNING_BANK_3 => synth.change_tuning_bank(2),
-                                    TUNING_BANK_4 => synth.change_tuning_bank(3),
-                                    TUNING_BANK_5 => synth.change_tuning_bank(4),
-                                    TUNING_BANK_6 => synth.change_tuning_bank(5),
-                                    TUNING_BANK_7 => synth.change_tuning_bank(6),
-                                    TUNING_BANK_8 => synth.change_tuning_bank(7),
-                                    SAVE_TIMBRE_PRESETS => write_settings_to_file(
-                                        settings_filename.as_str(),
-                                        synth.timbre_presets,
-                                    ),
-                                    _ => {}
-                                },
-                                // TODO
-                                // CONTROL => control.play(note),
-                                // MANUAL => synth.play(note),
-                                PEDALS if note == 24 => {
-                                    octave_pedal = true;
-                                }
-                                PEDALS if note >= 12 && note <= 23 => {
-                                    synth.change_tuning_bank(
-                                        note as usize - if octave_pedal { 0 } else { 12 },
-                                    );
-                                }
-                                PEDALS if note >= 24 && note <= 35 => {
-                                    synth.change_tuning_bank(note as usize - 12);
-                                }
-                                PEDALS => synth.play_fixed(note - 24),
-                                // _ => {}
-                                _ => synth.play_fixed(note),
-                            },
-                            Mode::Dynamic => {
-                                match channel {
-                                    CONTROL => match note {
-                                        C3..=H3 => {
-                                            synth.change_fundamental(note);
-                                        }
-                                        C4..=C5 => {
-                                            synth.change_tuning(note);
-                                        }
-                                        CIS5..=C6 => {
-                                            synth.change_tuning(note - 12);
-                                        }
-                                        _ => (),
-                                    },
-                                    MANUAL => {
-                                        synth.play(note);
-                                    }
-                                    PEDALS => match note {
-                                        C0..=H0 => {
-                                            synth.change_fundamental(note + 36);
-                                        }
-                                        C1..=H1 => {
-                                            synth.change_tuning(note + 36);
-                                        }
-                                        C2..=C5 => {
-                                            synth.play(note - 24);
-                                        }
-                                        _ => (),
-                                    },
-                                    MIXER => match note {
-                                        CIS1 => {
-                                            synth.toggle_modulator1_env_repeat();
-                                        }
-                                        D1 => {
-                                            synth.toggle_modulator2_env_repeat();
-                                        }
-                                        TIMBRE_BANK_1 => synth.change_timbre_bank(0),
-                                        TIMBRE_BANK_2 => synth.change_timbre_bank(1),
-                                        TIMBRE_BANK_3 => synth.change_timbre_bank(2),
-                                        TIMBRE_BANK_4 => synth.change_timbre_bank(3),
-                                        TIMBRE_BANK_5 => synth.change_timbre_bank(4),
-                                        TIMBRE_BANK_6 => synth.change_timbre_bank(5),
-                                        TIMBRE_BANK_7 => synth.change_timbre_bank(6),
-                                        TIMBRE_BANK_8 => synth.change_timbre_bank(7),
-                                        TUNING_BANK_1 => synth.change_tuning_bank(0),
-                                        TUNING_BANK_2 => synth.change_tuning_bank(1),
-                                        TUNING_BANK_3 => synth.change_tuning_bank(2),
-                                        TUNING_BANK_4 => synth.change_tuning_bank(3),
-                                        TUNING_BANK_5 => synth.change_tuning_bank(4),
-                                        TUNING_BANK_6 => synth.change_tuning_bank(5),
-                                        TUNING_BANK_7 => synth.change_tuning_bank(6),
-                                        TUNING_BANK_8 => synth.change_tuning_bank(7),
-                                        SAVE_TIMBRE_PRESETS => write_settings_to_file(
-                                            settings_filename.as_str(),
-                                            synth.timbre_presets,
-                                        ),
-                                        _ => {}
-                                    },
-                                    // _ => unreachable!(),
-                                    _ => {}
-                                }
-                            }
-                        }
-                    }
+                Change::Tunings(filename, Ok(tunings)) => {
+                    println!("Reloading tunings {filename}");
+                    self.set_tunings(tunings);
                 }
-                EventType::Controller => match event.get_data() {
-                    Some(EvCtrl {
-                        param,
-                        value,
-                        channel,
-                        ..
-                    }) => match channel {
-                        MANUAL => match param {
-                            DAMPER => {
-                                if value == 127 {
-                                    synth.enable_sustain()
-                                } else {
-                                    synth.disable_sustain()
-                                }
-                            }
-                            _ => {}
-                        },
-                        EXPRESSION => match param {
-                            VOLUME => synth.set_gain(value as u16 * 512),
-                            VIBRATO => synth.set_vibrato((value / 14) as f64),
-                            _ => {}
-                        },
-                        MIXER => match param {
-                            OSCILLATOR1_WAVEFORM => {
-                                let waveform = match value / (128 / 4) {
-                                    0 => Waveform::Sine,
-                                    1 => Waveform::Pulse,
-                                    2 => Waveform::Triangle,
-                                    3 => Waveform::Sawtooth,
-                                    _ => unreachable!(),
-                                };
-                                synth.set_oscillator1_waveform(waveform);
-                            }
-                            OSCILLATOR2_WAVEFORM => {
-                                let waveform = match value / (128 / 4) {
-                                    0 => Waveform::Sine,
-                                    1 => Waveform::Pulse,
-                                    2 => Waveform::Triangle,
-                                    3 => Waveform::Sawtooth,
-                                    _ => unreachable!(),
-                                };
-                                synth.set_oscillator2_waveform(waveform);
-                            }
-                            MODULATOR1_WAVEFORM => {
-                                let waveform = match value / (128 / 4) {
-                                    0 => Waveform::Sine,
-                                    1 => Waveform::Pulse,
-                                    2 => Waveform::Triangle,
-                                    3 => Waveform::Sawtooth,
-                                    _ => unreachable!(),
-                                };
-                                synth.set_modulator1_waveform(waveform);
-                            }
-                            MODULATOR2_WAVEFORM => {
-                                let waveform = match value / (128 / 4) {
-                                    0 => Waveform::Sine,
-                                    1 => Waveform::Pulse,
-                                    2 => Waveform::Triangle,
-                                    3 => Waveform::Sawtooth,
-                                    _ => unreachable!(),
-                                };
-                                synth.set_modulator2_waveform(waveform);
-                            }
-                            OSCILLATOR1_DUTY => synth.set_oscillator1_duty(value as u8),
-                            OSCILLATOR2_DUTY => synth.set_oscillator2_duty(value as u8),
-                            MODULATOR1_RATIO => synth.set_modulator1_ratio(value as u8),
-                            MODULATOR1_AMOUNT => synth.set_modulator1_amount(value as u8),
-                            MODULATOR1_DUTY => synth.set_modulator1_duty(value as u8),
-                            MODULATOR1_ATTACK => synth.set_modulator1_attack(value as u8),
-                            MODULATOR1_DECAY => synth.set_modulator1_decay(value as u8),
-                            MODULATOR1_SUSTAIN => synth.set_modulator1_sustain(value as u8),
-                            MODULATOR1_RELEASE => synth.set_modulator1_release(value as u8),
-                            MODULATOR2_RATIO => synth.set_modulator2_ratio(value as u8),
-                            MODULATOR2_AMOUNT => synth.set_modulator2_amount(value as u8),
-                            MODULATOR2_DUTY => synth.set_modulator2_duty(value as u8),
-                            MODULATOR2_ATTACK => synth.set_modulator2_attack(value as u8),
-                            MODULATOR2_DECAY => synth.set_modulator2_decay(value as u8),
-                            MODULATOR2_SUSTAIN => synth.set_modulator2_sustain(value as u8),
-                            MODULATOR2_RELEASE => synth.set_modulator2_release(value as u8),
-                            ATTACK => synth.set_attack(value as u8),
-                            DECAY => synth.set_decay(value as u8),
-                            SUSTAIN => synth.set_sustain(value as u8),
-                            RELEASE => synth.set_release(value as u8),
-                            ENV_LENGTH => synth.set_envelope_length(value as u8),
-                            MOD1_ENV_LENGTH => synth.set_modulator1_envelope_length(value as u8),
-                            MOD2_ENV_LENGTH => synth.set_modulator2_envelope_length(value as u8),
-                            MOD1_RATIO_SPECTRUM => synth.set_modulator1_ratio_spectrum(value as u8),
-                            MOD1_AMOUNT_SPECTRUM => {
-                                synth.set_modulator1_amount_spectrum(value as u8)
-                            }
-                            MOD2_RATIO_SPECTRUM => synth.set_modulator2_ratio_spectrum(value as u8),
-                            MOD2_AMOUNT_SPECTRUM => {
-                                synth.set_modulator2_amount_spectrum(value as u8)
-                            }
-                            VIBRATO_DEPTH => synth.set_vibrato_depth(value as u8),
-                            OSCILLATOR_BALANCE => synth.set_oscillator_balance(value as u8),
-                            _ => {}
+                Change::Settings(_, Err(e)) | Change::Tunings(_, Err(e)) => {
+                    eprintln!("WARNING: {e:#}")
+                }
+            }
+        }
+    }
+
+    // Modes picked while playing outlive edits to the files, as long as the name stays
+    fn set_tunings(&mut self, mut tunings: Vec<Tuning>) {
+        for tuning in &mut tunings {
+            if let Some(old) = self.tunings.iter().find(|old| old.name == tuning.name) {
+                tuning.mode = old.mode;
+            }
+        }
+
+        for synth in self.divisions.iter_mut() {
+            synth.set_tuning_presets(tunings.iter().map(Tuning::preset).collect());
+        }
+        self.tunings = tunings;
+    }
+
+    // Bindings follow the mode of the selected division
+    fn handle_event(&mut self, event: MidiEvent) {
+        let selected = self.divisions.selected();
+        let mode = self.divisions.get(selected).mode;
+
+        if event
+            .channel()
+            .is_some_and(|channel| self.mpe.is_member(channel))
+        {
+            return self.handle_member_event(event);
+        }
+
+        match event {
+            MidiEvent::NoteOn {
+                channel,
+                note,
+                velocity,
+            } => {
+                if let Some(binding) = self.mapping.find_note(channel, note, mode) {
+                    // Named tuning presets keep their binding when the bank is reordered
+                    let value = match &binding.tuning {
+                        Some(name) => match self.divisions.get(selected).find_tuning(name) {
+                            Some(index) => index as i32,
+                            None => return eprintln!("WARNING: No tuning preset named {name}"),
                         },
-                        _ => {}
-                    },
+                        None => binding.note_value(note, self.shift),
+                    };
+
+                    self.pressed.insert((channel, note), mode);
+                    self.perform(binding.division, binding.action, value, velocity);
+                }
+            }
+            MidiEvent::NoteOff { channel, note } => {
+                let mode = self.pressed.remove(&(channel, note)).unwrap_or(mode);
+
+                if let Some(binding) = self.mapping.find_note(channel, note, mode)
+                    && let Some(release) = binding.release
+                {
+                    let value = binding.note_value(note, self.shift);
+
+                    self.perform(binding.division, release, value, 0);
+                }
+            }
+            MidiEvent::PitchBend { channel, value } => self
+                .divisions
+                .get_mut(self.mapping.division(channel, mode).unwrap_or(selected))
+                .set_pitch_bend(value),
+            MidiEvent::ChannelPressure { value, .. } => self
+                .divisions
+                .iter_mut()
+                .for_each(|synth| synth.set_channel_pressure(value)),
+            MidiEvent::TuningChange { notes } => self
+                .divisions
+                .iter_mut()
+                .for_each(|synth| synth.set_note_frequencies(&notes)),
+            // Only keys that play a voice have one to press on
+            MidiEvent::PolyPressure {
+                channel,
+                note,
+                value,
+            } => {
+                if let Some(binding) = self.mapping.find_note(channel, note, mode)
+                    && binding.action == Action::Play
+                    && let Ok(note) = u8::try_from(binding.note_value(note, self.shift))
+                {
+                    self.divisions
+                        .get_mut(binding.division.unwrap_or(selected))
+                        .set_poly_pressure(note, value);
+                }
+            }
+            MidiEvent::Controller {
+                channel,
+                param,
+                value,
+            } => {
+                match self.rpn.controller(channel, param, value) {
+                    // Stored in the division's timbre preset like a bound controller would
+                    Some((RpnTracker::PITCH_BEND_SENSITIVITY, semitones)) => self.perform(
+                        Some(self.mapping.division(channel, mode).unwrap_or(selected)),
+                        Action::SetBendRange,
+                        semitones as i32,
+                        127,
+                    ),
+                    Some((RpnTracker::MPE_CONFIGURATION, members))
+                        if channel == MpeZone::MANAGER =>
+                    {
+                        self.set_mpe_zone(members);
+                    }
                     _ => {}
-                },
-                _ => {}
+                }
+
+                match self
+                    .learn
+                    .controller(&self.mapping, channel, param, value, mode)
+                {
+                    Outcome::Ignored => {}
+                    Outcome::Consumed => return,
+                    Outcome::Learned(binding) => return self.learn_binding(binding),
+                }
+
+                if let Some(binding) = self.mapping.find_controller(channel, param, mode) {
+                    let value = match binding.action {
+                        Action::Damper => binding.is_at_top(binding.controller_value(value)) as i32,
+                        _ => binding.controller_value(value),
+                    };
+
+                    self.perform(binding.division, binding.action, value, 127);
+                }
+            }
+        }
+    }
+
+    // Member channels bypass the mapping, every note plays from the manual's tuning table
+    fn handle_member_event(&mut self, event: MidiEvent) {
+        self.divisions.accompany(Division::Manual);
+
+        let synth = self.divisions.get_mut(Division::Manual);
+
+        match event {
+            MidiEvent::NoteOn {
+                channel,
+                note,
+                velocity,
+            } => synth.play_member(channel, note, velocity),
+            MidiEvent::NoteOff { channel, note } => synth.silence_member(channel, note),
+            MidiEvent::PitchBend { channel, value } => synth.set_member_bend(channel, value),
+            MidiEvent::ChannelPressure { channel, value }
+            | MidiEvent::PolyPressure { channel, value, .. } => {
+                synth.set_member_pressure(channel, value)
+            }
+            MidiEvent::Controller {
+                channel,
+                param,
+                value,
+            } => {
+                if let Some((RpnTracker::PITCH_BEND_SENSITIVITY, semitones)) =
+                    self.rpn.controller(channel, param, value)
+                {
+                    synth.set_member_bend_range(semitones);
+                }
+
+                if param == MpeZone::SLIDE {
+                    synth.set_member_slide(channel, value.clamp(0, 127) as u8);
+                }
             }
+            MidiEvent::TuningChange { notes } => synth.set_note_frequencies(&notes),
         }
 
+        self.divisions.share_frame(Division::Manual);
+    }
+
+    fn learn_binding(&mut self, binding: Binding) {
+        println!(
+            "Learned {} for {} on channel {}",
+            binding.action.name(),
+            binding.trigger,
+            binding.channel
+        );
+        self.mapping.learn(binding);
+
+        let Some(filename) = &self.learned_mapping_filename else {
+            return eprintln!("WARNING: Learned bindings are not saved without a settings file");
+        };
+
+        if let Err(e) = self.mapping.save_learned(filename) {
+            eprintln!("WARNING: {e:#}");
+        }
+    }
+
+    fn perform(&mut self, division: Option<Division>, action: Action, value: i32, velocity: u8) {
+        match division {
+            Some(division) => self.perform_on(division, action, value, velocity),
+            // Every division sustains its own voices
+            None if matches!(action, Action::Damper) => {
+                for division in Division::ALL {
+                    self.perform_on(division, action, value, velocity);
+                }
+            }
+            // Tuning actions run and report once, the other divisions take on the result
+            None if action.is_global() => {
+                let selected = self.divisions.selected();
+
+                self.perform_on(selected, action, value, velocity);
+                self.divisions.share_tuning(selected);
+            }
+            None => self.perform_on(self.divisions.selected(), action, value, velocity),
+        }
+    }
+
+    fn perform_on(&mut self, division: Division, action: Action, value: i32, velocity: u8) {
+        self.divisions.accompany(division);
+
+        let synth = self.divisions.get_mut(division);
+        let byte = value.clamp(0, 127) as u8;
+
+        match action {
+            Action::Play | Action::Silence | Action::ChangeFundamental | Action::ChangeTuning
+                if !(0..=127).contains(&value) => {}
+            Action::Play => match synth.mode {
+                Mode::Fixed => synth.play_fixed(byte, velocity),
+                Mode::Dynamic => synth.play(byte, velocity),
+            },
+            Action::Silence => synth.silence(byte),
+            Action::ChangeFundamental => synth.change_fundamental(byte),
+            Action::ChangeTuning => synth.change_tuning(byte),
+            Action::ChangeDynamicTable => synth.change_dynamic_table(value as usize),
+            Action::ToggleTuningMode => synth.toggle_mode(),
+            Action::SelectDivision => {
+                if let Some(&division) = Division::ALL.get(value.max(0) as usize) {
+                    self.divisions.select(division);
+                }
+            }
+            Action::SetVolume => synth.set_volume(byte),
+            Action::TogglePrime => {
+                if !synth.toggle_prime(value as u32) {
+                    eprintln!("WARNING: No tuning table with prime {value}");
+                }
+            }
+            Action::SetPrimeLimit => synth.set_prime_limit(value.max(0) as u32),
+            Action::ChangeTimbreBank => synth.change_timbre_bank(value as usize),
+            Action::ChangeTuningBank => synth.change_tuning_bank(value as usize),
+            Action::NextTuningBank => synth.next_tuning_bank(),
+            Action::ToggleAdaptiveTuning => synth.toggle_adaptive_tuning(),
+            Action::ToggleDriftCompensation => synth.toggle_drift_compensation(),
+            Action::ReportDrift => synth.report_drift(),
+            Action::PreviousTuningBank => synth.previous_tuning_bank(),
+            // The tunings are shared, so every division playing this one follows
+            Action::ChangeMode => {
+                let index = synth.tuning_index();
+
+                if let Some(tuning) = self.tunings.get_mut(index) {
+                    tuning.mode = value.max(0) as usize;
+
+                    let frequencies = tuning.frequencies();
+
+                    for synth in self.divisions.iter_mut() {
+                        synth.set_tuning(index, frequencies);
+                    }
+                }
+            }
+            Action::ToggleModulator1EnvRepeat => synth.toggle_modulator1_env_repeat(),
+            Action::ToggleModulator2EnvRepeat => synth.toggle_modulator2_env_repeat(),
+            Action::SaveTimbrePresets => {
+                let filename = self.settings_filename.as_deref();
+
+                write_settings_to_file(
+                    filename.unwrap_or(DEFAULT_SETTINGS_FILENAME),
+                    synth.timbre_presets,
+                )
+            }
+            Action::ExportTuning => {
+                let filename = self.settings_filename.as_deref();
+                let prefix = exported_tuning_prefix(filename.unwrap_or(DEFAULT_SETTINGS_FILENAME));
+
+                if let Err(e) = export::export_tuning(&prefix, &synth.frequencies()) {
+                    eprintln!("WARNING: {e:#}");
+                }
+            }
+            Action::Learn => {
+                if let Some(binding) = self.learn.press() {
+                    self.learn_binding(binding);
+                }
+            }
+            Action::LearnSelect => {}
+            Action::EnableShift => self.shift = true,
+            Action::DisableShift => self.shift = false,
+            Action::Damper => {
+                if value > 0 {
+                    synth.enable_sustain()
+                } else {
+                    synth.disable_sustain()
+                }
+            }
+            Action::SetGain => synth.set_gain(value as u16),
+            Action::SetVibrato => synth.set_vibrato(value as f64),
+            Action::SetOscillator1Waveform => synth.set_oscillator1_waveform(waveform(value)),
+            Action::SetOscillator2Waveform => synth.set_oscillator2_waveform(waveform(value)),
+            Action::SetModulator1Waveform => synth.set_modulator1_waveform(waveform(value)),
+            Action::SetModulator2Waveform => synth.set_modulator2_waveform(waveform(value)),
+            Action::SetOscillator1Duty => synth.set_oscillator1_duty(byte),
+            Action::SetOscillator2Duty => synth.set_oscillator2_duty(byte),
+            Action::SetModulator1Ratio => synth.set_modulator1_ratio(byte),
+            Action::SetModulator1Amount => synth.set_modulator1_amount(byte),
+            Action::SetModulator1Duty => synth.set_modulator1_duty(byte),
+            Action::SetModulator1Attack => synth.set_modulator1_attack(byte),
+            Action::SetModulator1Decay => synth.set_modulator1_decay(byte),
+            Action::SetModulator1Sustain => synth.set_modulator1_sustain(byte),
+            Action::SetModulator1Release => synth.set_modulator1_release(byte),
+            Action::SetModulator2Ratio => synth.set_modulator2_ratio(byte),
+            Action::SetModulator2Amount => synth.set_modulator2_amount(byte),
+            Action::SetModulator2Duty => synth.set_modulator2_duty(byte),
+            Action::SetModulator2Attack => synth.set_modulator2_attack(byte),
+            Action::SetModulator2Decay => synth.set_modulator2_decay(byte),
+            Action::SetModulator2Sustain => synth.set_modulator2_sustain(byte),
+            Action::SetModulator2Release => synth.set_modulator2_release(byte),
+            Action::SetAttack => synth.set_attack(byte),
+            Action::SetDecay => synth.set_decay(byte),
+            Action::SetSustain => synth.set_sustain(byte),
+            Action::SetRelease => synth.set_release(byte),
+            Action::SetEnvelopeLength => synth.set_envelope_length(byte),
+            Action::SetModulator1EnvelopeLength => synth.set_modulator1_envelope_length(byte),
+            Action::SetModulator2EnvelopeLength => synth.set_modulator2_envelope_length(byte),
+            Action::SetModulator1RatioSpectrum => synth.set_modulator1_ratio_spectrum(byte),
+            Action::SetModulator1AmountSpectrum => synth.set_modulator1_amount_spectrum(byte),
+            Action::SetModulator2RatioSpectrum => synth.set_modulator2_ratio_spectrum(byte),
+            Action::SetModulator2AmountSpectrum => synth.set_modulator2_amount_spectrum(byte),
+            Action::SetVibratoDepth => synth.set_vibrato_depth(byte),
+            Action::SetOscillatorBalance => synth.set_oscillator_balance(byte),
+            Action::SetVelocityCurve => synth.set_velocity_curve(velocity_curve(value)),
+            Action::SetVelocityBrightness => synth.set_velocity_brightness(byte),
+            Action::SetBendRange => synth.set_bend_range(byte),
+            Action::SetBendMode => synth.set_bend_mode(bend_mode(value)),
+            Action::SetRetuneGlide => {
+                synth.set_retune_glide(value.clamp(0, u16::MAX as i32) as u16)
+            }
+            Action::SetPressureTarget => synth.set_pressure_target(pressure_target(value)),
+            Action::SetSlideTarget => synth.set_slide_target(pressure_target(value)),
+        }
+
+        if action.edits_timbre() {
+            self.divisions.share_timbre_preset(division);
+        }
+
+        self.divisions.share_frame(division);
+    }
+}
+
+fn perform<E: EventSource, A: AudioSink>(
+    io: &mut IO<E, A>,
+    instrument: &mut Instrument,
+) -> Result<()> {
+    while !io.is_finished() {
+        io.write(&mut instrument.divisions)?;
+
+        while let Some(event) = io.read()? {
+            instrument.handle_event(event);
+        }
+
+        instrument.reload();
+
         io.poll()?;
     }
+
+    io.finish()
+}
+
+pub fn run(options: Options) -> Result<()> {
+    match options {
+        Options::Render {
+            input,
+            output,
+            float,
+            tail,
+            synth_options,
+        } => {
+            let source = SmfSource::open(&input, tail.unwrap_or(2.0))?;
+            let mut instrument = Instrument::new(synth_options)?;
+
+            match output {
+                Some(output) => perform(
+                    &mut IO::new(source, WavSink::create(&output, float)?)?,
+                    &mut instrument,
+                ),
+                None => perform(&mut IO::new(source, NullSink::new())?, &mut instrument),
+            }
+        }
+        Options::Export {
+            output,
+            input,
+            synth_options,
+        } => {
+            let mut instrument = Instrument::new(synth_options)?;
+
+            if let Some(input) = input {
+                let source = SmfSource::open(&input, 0.0)?;
+
+                perform(&mut IO::new(source, NullSink::new())?, &mut instrument)?;
+            }
+
+            let divisions = &instrument.divisions;
+
+            export::export_tuning(&output, &divisions.get(divisions.selected()).frequencies())
+        }
+        Options::Play {
+            main_port,
+            aux_port,
+            expr_port,
+            mixer_port,
+            pedal_port,
+            card,
+            synth_options,
+        } => {
+            let mut instrument = Instrument::new(synth_options)?;
+            instrument.watch();
+
+            perform(
+                &mut IO::alsa(
+                    main_port, aux_port, expr_port, mixer_port, pedal_port, &card,
+                )?,
+                &mut instrument,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hw::{CHANNELS, MemorySink, SAMPLE_RATE};
+    use midly::num::{u4, u7, u28};
+    use midly::{
+        Format, Header, MetaMessage, MidiMessage, Smf, Timing, TrackEvent, TrackEventKind,
+    };
+
+    // Half a second of A4 on the manual, at the default 120 BPM
+    fn save_note(filename: &Path) {
+        let note = |delta, message| TrackEvent {
+            delta: u28::new(delta),
+            kind: TrackEventKind::Midi {
+                channel: u4::new(1),
+                message,
+            },
+        };
+        let key = u7::new(69);
+
+        let mut smf = Smf::new(Header::new(
+            Format::SingleTrack,
+            Timing::Metrical(480.into()),
+        ));
+        smf.tracks.push(vec![
+            note(
+                0,
+                MidiMessage::NoteOn {
+                    key,
+                    vel: u7::new(100),
+                },
+            ),
+            note(
+                480,
+                MidiMessage::NoteOff {
+                    key,
+                    vel: u7::new(0),
+                },
+            ),
+            TrackEvent {
+                delta: u28::new(0),
+                kind: TrackEventKind::Meta(MetaMessage::EndOfTrack),
+            },
+        ]);
+
+        smf.save(filename).unwrap();
+    }
+
+    fn rpn(channel: u8, param: u16, value: i32) -> [MidiEvent; 3] {
+        let controller = |param, value| MidiEvent::Controller {
+            channel,
+            param,
+            value,
+        };
+
+        [
+            controller(101, (param >> 7) as i32),
+            controller(100, (param & 0x7F) as i32),
+            controller(6, value),
+        ]
+    }
+
+    #[test]
+    fn channel_messages_reach_the_division_playing_on_the_channel() {
+        let mut instrument = Instrument::new(SynthOptions::default()).unwrap();
+
+        // The default mapping plays the pedal on channel 4
+        for event in rpn(4, RpnTracker::PITCH_BEND_SENSITIVITY, 7) {
+            instrument.handle_event(event);
+        }
+        instrument.handle_event(MidiEvent::PitchBend {
+            channel: 4,
+            value: 4096,
+        });
+
+        for division in Division::ALL {
+            let synth = instrument.divisions.get(division);
+            let settings = serde_json::to_value(synth.timbre_presets[0]).unwrap();
+
+            assert_eq!(settings["bend_range"], 7);
+            assert_eq!(
+                synth.pitch_bend(),
+                if division == Division::Pedal {
+                    0.5
+                } else {
+                    0.0
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn mpe_zones_leave_the_channels_after_them_to_the_mapping() {
+        let mut instrument = Instrument::new(SynthOptions {
+            mpe: Some(2),
+            ..SynthOptions::default()
+        })
+        .unwrap();
+
+        assert!(instrument.mpe.is_member(2));
+        assert!(!instrument.mpe.is_member(3));
+
+        // A configuration message on the manager channel resizes the zone
+        for event in rpn(MpeZone::MANAGER, RpnTracker::MPE_CONFIGURATION, 0) {
+            instrument.handle_event(event);
+        }
+
+        assert!(!instrument.mpe.is_member(1));
+    }
+
+    #[test]
+    fn divisions_adapt_within_one_frame() {
+        let mut instrument = Instrument::new(SynthOptions {
+            adaptive: true,
+            ..SynthOptions::default()
+        })
+        .unwrap();
+
+        let frame = |instrument: &Instrument, division| instrument.divisions.get(division).frame();
+        let frequencies =
+            |instrument: &Instrument, division| instrument.divisions.get(division).frequencies();
+
+        instrument.perform(Some(Division::Manual), Action::Play, 60, 100);
+        let c = frequencies(&instrument, Division::Manual)[60];
+
+        // The pedal's note is fitted around the held C, which keeps its pitch
+        instrument.perform(Some(Division::Pedal), Action::Play, 64, 100);
+        let pedal = frequencies(&instrument, Division::Pedal);
+
+        assert_eq!(pedal[60], c);
+        assert!((pedal[64] / pedal[60] - 1.25).abs() < 1e-9);
+        assert_eq!(frequencies(&instrument, Division::Manual), pedal);
+
+        // Moving the fundamental from any division moves it for all
+        instrument.perform(Some(Division::Control), Action::ChangeFundamental, 62, 127);
+
+        for division in Division::ALL {
+            assert_eq!(
+                frame(&instrument, division),
+                frame(&instrument, Division::Manual)
+            );
+        }
+
+        assert_ne!(frequencies(&instrument, Division::Pedal), pedal);
+    }
+
+    #[test]
+    fn modes_reach_every_division_and_survive_reloads() {
+        let mut instrument = Instrument::new(SynthOptions {
+            tuning_preset_filename: Some("quietness".to_string()),
+            ..SynthOptions::default()
+        })
+        .unwrap();
+        let frequencies =
+            |instrument: &Instrument, division| instrument.divisions.get(division).frequencies();
+        let before = frequencies(&instrument, Division::Pedal);
+
+        instrument.perform(Some(Division::Manual), Action::ChangeMode, 2, 127);
+
+        let after = frequencies(&instrument, Division::Manual);
+
+        assert_ne!(after, before);
+        assert_eq!(frequencies(&instrument, Division::Pedal), after);
+
+        instrument.set_tunings(load_tunings("quietness", 440.0, 69).unwrap());
+
+        assert_eq!(instrument.tunings[0].mode, 2);
+        assert_eq!(frequencies(&instrument, Division::Control), after);
+    }
+
+    fn dynamic_table(name: &str, intervals: &str) -> Result<DynamicTable> {
+        let filename =
+            std::env::temp_dir().join(format!("instr-{}-{name}.json", std::process::id()));
+        let filename = filename.to_string_lossy().into_owned();
+        std::fs::write(&filename, intervals).unwrap();
+
+        let table = load_dynamic_table(&filename);
+        std::fs::remove_file(&filename).unwrap();
+
+        table
+    }
+
+    #[test]
+    fn dynamic_tables_must_rise_from_the_unison() {
+        let semitones = "1.0, 1.06, 1.12, 1.19, 1.26, 1.33, 1.41, 1.5, 1.59, 1.68, 1.78, 1.89";
+
+        assert!(dynamic_table("equal", &format!("[{semitones}]")).is_ok());
+
+        let error = |name, intervals: &str| dynamic_table(name, intervals).unwrap_err().to_string();
+
+        assert!(
+            error("gap", &format!("[{}]", semitones.replace("1.12", "0")))
+                .contains("has 0 on semitone 2")
+        );
+        assert!(
+            error("falling", &format!("[{}]", semitones.replace("1.5", "1.3")))
+                .contains("has 1.3 on semitone 7")
+        );
+        assert!(
+            error("octave", &format!("[{}]", semitones.replace("1.89", "2.0")))
+                .contains("has 2 on semitone 11")
+        );
+        assert!(
+            error(
+                "shifted",
+                &format!("[{}]", semitones.replacen("1.0", "1.01", 1))
+            )
+            .contains("must start at 1/1, not 1.01")
+        );
+    }
+
+    #[test]
+    fn renders_a_midi_file_into_memory() {
+        let filename = std::env::temp_dir().join(format!("instr-{}.mid", std::process::id()));
+        save_note(&filename);
+
+        let source = SmfSource::open(filename.to_str().unwrap(), 0.1);
+        std::fs::remove_file(&filename).unwrap();
+
+        let mut io = IO::new(source.unwrap(), MemorySink::new()).unwrap();
+        let mut instrument = Instrument::new(SynthOptions::default()).unwrap();
+
+        perform(&mut io, &mut instrument).unwrap();
+
+        let samples = io.sink().samples();
+
+        // Stops right at the end of the tail
+        assert_eq!(
+            samples.len(),
+            SAMPLE_RATE as usize * 6 / 10 * CHANNELS as usize
+        );
+        assert!(samples.iter().any(|&sample| sample != 0));
+    }
 }
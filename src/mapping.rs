@@ -0,0 +1,455 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use synth::oscillator::Waveform;
+use synth::pressure::PressureTarget;
+use synth::velocity::VelocityCurve;
+use synth::{BendMode, Mode};
+
+use crate::division::Division;
+
+const DEFAULT_MAPPING: &str = include_str!("../mapping.json");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Play,
+    Silence,
+    ChangeFundamental,
+    ChangeTuning,
+    TogglePrime,
+    SetPrimeLimit,
+    ChangeDynamicTable,
+    ChangeTimbreBank,
+    ChangeTuningBank,
+    NextTuningBank,
+    PreviousTuningBank,
+    ToggleAdaptiveTuning,
+    ToggleDriftCompensation,
+    ReportDrift,
+    ChangeMode,
+    ToggleTuningMode,
+    SelectDivision,
+    SetVolume,
+    ToggleModulator1EnvRepeat,
+    ToggleModulator2EnvRepeat,
+    SaveTimbrePresets,
+    ExportTuning,
+    Learn,
+    LearnSelect,
+    EnableShift,
+    DisableShift,
+    Damper,
+    SetGain,
+    SetVibrato,
+    SetOscillator1Waveform,
+    SetOscillator2Waveform,
+    SetModulator1Waveform,
+    SetModulator2Waveform,
+    SetOscillator1Duty,
+    SetOscillator2Duty,
+    SetModulator1Ratio,
+    SetModulator1Amount,
+    SetModulator1Duty,
+    SetModulator1Attack,
+    SetModulator1Decay,
+    SetModulator1Sustain,
+    SetModulator1Release,
+    SetModulator2Ratio,
+    SetModulator2Amount,
+    SetModulator2Duty,
+    SetModulator2Attack,
+    SetModulator2Decay,
+    SetModulator2Sustain,
+    SetModulator2Release,
+    SetAttack,
+    SetDecay,
+    SetSustain,
+    SetRelease,
+    SetEnvelopeLength,
+    SetModulator1EnvelopeLength,
+    SetModulator2EnvelopeLength,
+    SetModulator1RatioSpectrum,
+    SetModulator1AmountSpectrum,
+    SetModulator2RatioSpectrum,
+    SetModulator2AmountSpectrum,
+    SetVibratoDepth,
+    SetOscillatorBalance,
+    SetVelocityCurve,
+    SetVelocityBrightness,
+    SetBendRange,
+    SetBendMode,
+    SetPressureTarget,
+    SetSlideTarget,
+    SetRetuneGlide,
+}
+
+impl Action {
+    // Everything a knob can sensibly be bound to
+    pub const LEARNABLE: [Action; 46] = [
+        Action::Damper,
+        Action::SetGain,
+        Action::SetVibrato,
+        Action::SetOscillator1Waveform,
+        Action::SetOscillator2Waveform,
+        Action::SetModulator1Waveform,
+        Action::SetModulator2Waveform,
+        Action::SetOscillator1Duty,
+        Action::SetOscillator2Duty,
+        Action::SetModulator1Ratio,
+        Action::SetModulator1Amount,
+        Action::SetModulator1Duty,
+        Action::SetModulator1Attack,
+        Action::SetModulator1Decay,
+        Action::SetModulator1Sustain,
+        Action::SetModulator1Release,
+        Action::SetModulator2Ratio,
+        Action::SetModulator2Amount,
+        Action::SetModulator2Duty,
+        Action::SetModulator2Attack,
+        Action::SetModulator2Decay,
+        Action::SetModulator2Sustain,
+        Action::SetModulator2Release,
+        Action::SetAttack,
+        Action::SetDecay,
+        Action::SetSustain,
+        Action::SetRelease,
+        Action::SetEnvelopeLength,
+        Action::SetModulator1EnvelopeLength,
+        Action::SetModulator2EnvelopeLength,
+        Action::SetModulator1RatioSpectrum,
+        Action::SetModulator1AmountSpectrum,
+        Action::SetModulator2RatioSpectrum,
+        Action::SetModulator2AmountSpectrum,
+        Action::SetVibratoDepth,
+        Action::SetOscillatorBalance,
+        Action::SetVelocityCurve,
+        Action::SetVelocityBrightness,
+        Action::SetBendRange,
+        Action::SetBendMode,
+        Action::SetPressureTarget,
+        Action::SetSlideTarget,
+        Action::SetRetuneGlide,
+        Action::SetPrimeLimit,
+        Action::SetVolume,
+        Action::ChangeMode,
+    ];
+
+    pub fn default_range(self) -> Option<[i32; 2]> {
+        match self {
+            Action::SetGain => Some([0, u16::MAX as i32]),
+            Action::SetVibrato => Some([0, 9]),
+            Action::SetOscillator1Waveform
+            | Action::SetOscillator2Waveform
+            | Action::SetModulator1Waveform
+            | Action::SetModulator2Waveform
+            | Action::SetVelocityCurve => Some([0, 3]),
+            Action::SetBendRange => Some([0, 24]),
+            Action::SetBendMode => Some([0, 1]),
+            Action::ChangeMode => Some([0, 11]),
+            Action::SetPressureTarget | Action::SetSlideTarget => Some([0, 5]),
+            Action::SetRetuneGlide => Some([0, 2000]),
+            Action::SetPrimeLimit => Some([3, 31]),
+            Action::SelectDivision => Some([0, 2]),
+            _ => None,
+        }
+    }
+
+    // Tuning and the damper reach every division unless the binding names one
+    pub fn is_global(self) -> bool {
+        matches!(
+            self,
+            Action::ChangeFundamental
+                | Action::ChangeTuning
+                | Action::TogglePrime
+                | Action::SetPrimeLimit
+                | Action::ChangeDynamicTable
+                | Action::ChangeTuningBank
+                | Action::NextTuningBank
+                | Action::PreviousTuningBank
+                | Action::ToggleAdaptiveTuning
+                | Action::ToggleDriftCompensation
+                | Action::ChangeMode
+                | Action::ToggleTuningMode
+                | Action::Damper
+        )
+    }
+
+    // Changes the timbre preset the division is playing
+    pub fn edits_timbre(self) -> bool {
+        matches!(
+            self,
+            Action::SetOscillator1Waveform
+                | Action::SetOscillator2Waveform
+                | Action::SetModulator1Waveform
+                | Action::SetModulator2Waveform
+                | Action::SetOscillator1Duty
+                | Action::SetOscillator2Duty
+                | Action::SetModulator1Ratio
+                | Action::SetModulator1Amount
+                | Action::SetModulator1Duty
+                | Action::SetModulator1Attack
+                | Action::SetModulator1Decay
+                | Action::SetModulator1Sustain
+                | Action::SetModulator1Release
+                | Action::SetModulator2Ratio
+                | Action::SetModulator2Amount
+                | Action::SetModulator2Duty
+                | Action::SetModulator2Attack
+                | Action::SetModulator2Decay
+                | Action::SetModulator2Sustain
+                | Action::SetModulator2Release
+                | Action::SetAttack
+                | Action::SetDecay
+                | Action::SetSustain
+                | Action::SetRelease
+                | Action::SetEnvelopeLength
+                | Action::SetModulator1EnvelopeLength
+                | Action::SetModulator2EnvelopeLength
+                | Action::SetModulator1RatioSpectrum
+                | Action::SetModulator1AmountSpectrum
+                | Action::SetModulator2RatioSpectrum
+                | Action::SetModulator2AmountSpectrum
+                | Action::SetVibratoDepth
+                | Action::SetOscillatorBalance
+                | Action::SetVelocityCurve
+                | Action::SetVelocityBrightness
+                | Action::SetBendRange
+                | Action::SetBendMode
+                | Action::SetRetuneGlide
+                | Action::SetPressureTarget
+                | Action::SetSlideTarget
+        )
+    }
+
+    pub fn name(self) -> String {
+        serde_json::to_string(&self)
+            .unwrap_or_default()
+            .trim_matches('"')
+            .to_string()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    Note(u8),
+    Notes([u8; 2]),
+    Cc(u32),
+}
+
+fn is_zero(value: &i32) -> bool {
+    *value == 0
+}
+
+impl fmt::Display for Trigger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Trigger::Note(note) => write!(f, "note {note}"),
+            Trigger::Notes([low, high]) => write!(f, "notes {low}-{high}"),
+            Trigger::Cc(param) => write!(f, "controller {param}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Binding {
+    pub channel: u8,
+    #[serde(flatten)]
+    pub trigger: Trigger,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+    pub action: Action,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<Action>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub argument: Option<i32>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub offset: i32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub shift: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<[i32; 2]>,
+    // Tuning preset picked by name rather than by the argument
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tuning: Option<String>,
+    // Without one the action goes to the selected division
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub division: Option<Division>,
+}
+
+impl Binding {
+    pub fn controller(channel: u8, param: u32, action: Action, range: Option<[i32; 2]>) -> Self {
+        Self {
+            channel,
+            trigger: Trigger::Cc(param),
+            mode: None,
+            action,
+            release: None,
+            argument: None,
+            offset: 0,
+            shift: 0,
+            range,
+            tuning: None,
+            division: None,
+        }
+    }
+
+    fn applies(&self, channel: u8, mode: Mode) -> bool {
+        self.channel == channel && self.mode.is_none_or(|m| m == mode)
+    }
+
+    pub fn matches_note(&self, channel: u8, note: u8, mode: Mode) -> bool {
+        self.applies(channel, mode)
+            && match self.trigger {
+                Trigger::Note(n) => n == note,
+                Trigger::Notes([low, high]) => (low..=high).contains(&note),
+                Trigger::Cc(_) => false,
+            }
+    }
+
+    pub fn matches_controller(&self, channel: u8, param: u32, mode: Mode) -> bool {
+        self.applies(channel, mode) && self.trigger == Trigger::Cc(param)
+    }
+
+    pub fn note_value(&self, note: u8, shifted: bool) -> i32 {
+        self.argument.unwrap_or(note as i32) + self.offset + if shifted { self.shift } else { 0 }
+    }
+
+    // Splits the 128 controller values evenly over the inclusive range
+    pub fn controller_value(&self, value: i32) -> i32 {
+        let [low, high] = self.range.unwrap_or([0, 127]);
+
+        self.argument
+            .unwrap_or(low + (value as i64 * (high - low + 1) as i64 / 128) as i32)
+    }
+
+    pub fn is_at_top(&self, value: i32) -> bool {
+        value >= self.range.map_or(127, |[_, high]| high)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Mapping {
+    bindings: Vec<Binding>,
+    // Saved on their own, so they apply on top of whichever mapping is loaded
+    #[serde(skip)]
+    learned: Vec<Binding>,
+}
+
+impl Default for Mapping {
+    fn default() -> Self {
+        serde_json::from_str(DEFAULT_MAPPING).expect("Built-in mapping is malformed")
+    }
+}
+
+impl Mapping {
+    pub fn load(filename: &str) -> Result<Self> {
+        let file =
+            File::open(filename).with_context(|| format!("Can't open mapping {filename}"))?;
+
+        serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("Malformed mapping {filename}"))
+    }
+
+    pub fn load_learned(&mut self, filename: &str) -> Result<()> {
+        let file = File::open(filename)
+            .with_context(|| format!("Can't open learned bindings {filename}"))?;
+        let learned: Vec<Binding> = serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("Malformed learned bindings {filename}"))?;
+
+        // Learning inserts at the front, so going backwards keeps the saved order
+        for binding in learned.into_iter().rev() {
+            self.learn(binding);
+        }
+
+        Ok(())
+    }
+
+    pub fn save_learned(&self, filename: &str) -> Result<()> {
+        let file = File::create(filename)
+            .with_context(|| format!("Can't create learned bindings {filename}"))?;
+
+        serde_json::to_writer_pretty(file, &self.learned)
+            .with_context(|| format!("Can't write learned bindings {filename}"))
+    }
+
+    // Learned bindings replace whatever the control did before
+    pub fn learn(&mut self, binding: Binding) {
+        let replaces = |b: &Binding| {
+            b.channel == binding.channel && b.trigger == binding.trigger && b.mode == binding.mode
+        };
+
+        self.bindings.retain(|b| !replaces(b));
+        self.learned.retain(|b| !replaces(b));
+        self.bindings.insert(0, binding.clone());
+        self.learned.insert(0, binding);
+    }
+
+    // Bindings are tried in order, so earlier entries shadow later ones
+    pub fn find_note(&self, channel: u8, note: u8, mode: Mode) -> Option<Binding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.matches_note(channel, note, mode))
+            .cloned()
+    }
+
+    pub fn channels(&self) -> impl Iterator<Item = u8> {
+        self.bindings.iter().map(|binding| binding.channel)
+    }
+
+    // The division a channel plays, for channel messages that no binding names
+    pub fn division(&self, channel: u8, mode: Mode) -> Option<Division> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.applies(channel, mode) && binding.action == Action::Play)
+            .and_then(|binding| binding.division)
+    }
+
+    pub fn find_controller(&self, channel: u8, param: u32, mode: Mode) -> Option<Binding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.matches_controller(channel, param, mode))
+            .cloned()
+    }
+}
+
+pub fn waveform(value: i32) -> Waveform {
+    match value {
+        ..=0 => Waveform::Sine,
+        1 => Waveform::Pulse,
+        2 => Waveform::Triangle,
+        _ => Waveform::Sawtooth,
+    }
+}
+
+pub fn velocity_curve(value: i32) -> VelocityCurve {
+    match value {
+        ..=0 => VelocityCurve::Flat,
+        1 => VelocityCurve::Linear,
+        2 => VelocityCurve::Soft,
+        _ => VelocityCurve::Hard,
+    }
+}
+
+pub fn bend_mode(value: i32) -> BendMode {
+    if value > 0 {
+        BendMode::ScaleSteps
+    } else {
+        BendMode::Semitones
+    }
+}
+
+pub fn pressure_target(value: i32) -> PressureTarget {
+    match value {
+        ..=0 => PressureTarget::None,
+        1 => PressureTarget::VibratoDepth,
+        2 => PressureTarget::Modulator1Amount,
+        3 => PressureTarget::Modulator2Amount,
+        4 => PressureTarget::OscillatorBalance,
+        _ => PressureTarget::Gain,
+    }
+}
@@ -1,11 +1,125 @@
 use std::ffi::CString;
 
 use alsa::seq;
-use alsa::seq::{Event, EventType, Input, PortInfo};
+use alsa::seq::{EvCtrl, EvNote, EventType, Input, PortInfo};
+use alsa::{Direction, PollDescriptors};
 use anyhow::Result;
 
+use crate::hw::EventSource;
+use crate::mts;
+
+#[derive(Clone, Debug)]
+pub enum MidiEvent {
+    NoteOn { channel: u8, note: u8, velocity: u8 },
+    NoteOff { channel: u8, note: u8 },
+    Controller { channel: u8, param: u32, value: i32 },
+    PitchBend { channel: u8, value: i16 },
+    ChannelPressure { channel: u8, value: u8 },
+    PolyPressure { channel: u8, note: u8, value: u8 },
+    // MTS bulk dump or single note tuning change
+    TuningChange { notes: Vec<(u8, f64)> },
+}
+
+impl MidiEvent {
+    pub fn channel(&self) -> Option<u8> {
+        match *self {
+            MidiEvent::NoteOn { channel, .. }
+            | MidiEvent::NoteOff { channel, .. }
+            | MidiEvent::Controller { channel, .. }
+            | MidiEvent::PitchBend { channel, .. }
+            | MidiEvent::ChannelPressure { channel, .. }
+            | MidiEvent::PolyPressure { channel, .. } => Some(channel),
+            MidiEvent::TuningChange { .. } => None,
+        }
+    }
+
+    // Running status senders encode note offs as note ons with zero velocity
+    pub fn note_on(channel: u8, note: u8, velocity: u8) -> Self {
+        if velocity == 0 {
+            MidiEvent::NoteOff { channel, note }
+        } else {
+            MidiEvent::NoteOn {
+                channel,
+                note,
+                velocity,
+            }
+        }
+    }
+}
+
+// Follows the registered parameter number selected on each channel
+pub struct RpnTracker {
+    selected: [u16; 16],
+}
+
+impl RpnTracker {
+    pub const PITCH_BEND_SENSITIVITY: u16 = 0;
+    pub const MPE_CONFIGURATION: u16 = 6;
+    const NULL: u16 = 0x3FFF;
+    const DATA_ENTRY: u32 = 6;
+    const RPN_LSB: u32 = 100;
+    const RPN_MSB: u32 = 101;
+
+    pub fn new() -> Self {
+        Self {
+            selected: [Self::NULL; 16],
+        }
+    }
+
+    // Returns the parameter and its new value when a data entry arrives for a selected RPN
+    pub fn controller(&mut self, channel: u8, param: u32, value: i32) -> Option<(u16, u8)> {
+        let selected = self.selected.get_mut(channel as usize)?;
+        let value = value.clamp(0, 127) as u16;
+
+        match param {
+            Self::RPN_MSB => *selected = (*selected & 0x7F) | value << 7,
+            Self::RPN_LSB => *selected = (*selected & !0x7F) | value,
+            Self::DATA_ENTRY if *selected != Self::NULL => return Some((*selected, value as u8)),
+            _ => {}
+        }
+
+        None
+    }
+}
+
+impl Default for RpnTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// MPE lower zone: channel 0 manages the zone and each following member channel carries
+// a single note
+// TODO upper zone
+#[derive(Clone, Copy, Default)]
+pub struct MpeZone {
+    members: u8,
+}
+
+impl MpeZone {
+    pub const MANAGER: u8 = 0;
+    pub const SLIDE: u32 = 74;
+    pub const MAX_MEMBERS: u8 = 15;
+
+    pub fn new(members: u8) -> Self {
+        Self {
+            members: members.min(Self::MAX_MEMBERS),
+        }
+    }
+
+    pub fn members(self) -> u8 {
+        self.members
+    }
+
+    pub fn is_member(self, channel: u8) -> bool {
+        (1..=self.members).contains(&channel)
+    }
+}
+
 pub struct MidiInputStream {
     device: alsa::Seq,
+    // Long SysEx messages arrive in chunks
+    sysex: Vec<u8>,
 }
 
 impl MidiInputStream {
@@ -18,6 +132,7 @@ impl MidiInputStream {
     ) -> Result<Self> {
         Ok(Self {
             device: Self::open_midi_device(main_port, aux_port, expr_port, mixer_port, pedal_port)?,
+            sysex: Vec::new(),
         })
     }
 
@@ -25,7 +140,7 @@ impl MidiInputStream {
         &self.device
     }
 
-    pub fn input(&self) -> Input {
+    pub fn input(&self) -> Input<'_> {
         self.device.input()
     }
 
@@ -83,15 +198,92 @@ impl MidiInputStream {
         Ok(s)
     }
 
-    pub fn read_midi_event<'a>(mut input: Input) -> Result<Option<Event<'a>>> {
+    pub fn read_midi_event(mut input: Input, sysex: &mut Vec<u8>) -> Result<Option<MidiEvent>> {
         if input.event_input_pending(true)? == 0 {
             return Ok(None);
         }
-        let event = input.event_input()?.into_owned();
+        let event = input.event_input()?;
 
         Ok(match event.get_type() {
-            EventType::Noteon | EventType::Noteoff | EventType::Controller => Some(event),
+            EventType::Noteon => event.get_data().map(
+                |EvNote {
+                     channel,
+                     note,
+                     velocity,
+                     ..
+                 }| MidiEvent::note_on(channel, note, velocity),
+            ),
+            EventType::Noteoff => event
+                .get_data()
+                .map(|EvNote { channel, note, .. }| MidiEvent::NoteOff { channel, note }),
+            EventType::Controller => event.get_data().map(
+                |EvCtrl {
+                     channel,
+                     param,
+                     value,
+                     ..
+                 }| MidiEvent::Controller {
+                    channel,
+                    param,
+                    value,
+                },
+            ),
+            EventType::Pitchbend => {
+                event
+                    .get_data()
+                    .map(|EvCtrl { channel, value, .. }| MidiEvent::PitchBend {
+                        channel,
+                        value: value as i16,
+                    })
+            }
+            EventType::Chanpress => {
+                event
+                    .get_data()
+                    .map(|EvCtrl { channel, value, .. }| MidiEvent::ChannelPressure {
+                        channel,
+                        value: value.clamp(0, 127) as u8,
+                    })
+            }
+            EventType::Keypress => event.get_data().map(
+                |EvNote {
+                     channel,
+                     note,
+                     velocity,
+                     ..
+                 }| MidiEvent::PolyPressure {
+                    channel,
+                    note,
+                    value: velocity,
+                },
+            ),
+            EventType::Sysex => {
+                let data = event.get_ext().unwrap_or_default();
+
+                if data.first() == Some(&0xF0) {
+                    sysex.clear();
+                }
+                sysex.extend_from_slice(data);
+
+                if sysex.last() == Some(&0xF7) {
+                    let notes = mts::parse(sysex);
+                    sysex.clear();
+
+                    notes.map(|notes| MidiEvent::TuningChange { notes })
+                } else {
+                    None
+                }
+            }
             _ => None,
         })
     }
 }
+
+impl EventSource for MidiInputStream {
+    fn read(&mut self, _position: u64) -> Result<Option<MidiEvent>> {
+        Self::read_midi_event(self.device.input(), &mut self.sysex)
+    }
+
+    fn descriptors(&self) -> Result<Vec<alsa::poll::pollfd>> {
+        Ok((self.device(), Some(Direction::Capture)).get()?)
+    }
+}
@@ -0,0 +1,208 @@
+// MIDI Tuning Standard messages, with or without the leading 0xF0
+
+const NON_REAL_TIME: u8 = 0x7E;
+const REAL_TIME: u8 = 0x7F;
+const MIDI_TUNING: u8 = 0x08;
+const BULK_DUMP: u8 = 0x01;
+const NOTE_CHANGE: u8 = 0x02;
+const BANK_NOTE_CHANGE: u8 = 0x07;
+const NAME_LENGTH: usize = 16;
+// Marks notes the message leaves alone
+const NO_CHANGE: [u8; 3] = [0x7F, 0x7F, 0x7F];
+
+// Semitone and 14-bit fraction of a semitone above it, in 12-TET from A440
+pub fn frequency([semitone, msb, lsb]: [u8; 3]) -> f64 {
+    let fraction = ((msb as u16) << 7 | lsb as u16) as f64 / 16384.0;
+
+    440.0 * 2.0_f64.powf((semitone as f64 + fraction - 69.0) / 12.0)
+}
+
+// Nearest representable entry, frequencies outside the MTS range are left alone
+pub fn entry(freq: f64) -> [u8; 3] {
+    let semitones = 69.0 + 12.0 * (freq / 440.0).log2();
+
+    if !(0.0..128.0).contains(&semitones) {
+        return NO_CHANGE;
+    }
+
+    let fraction = ((semitones.fract() * 16384.0).round() as u16).min(16383);
+    let entry = [
+        semitones as u8,
+        (fraction >> 7) as u8,
+        (fraction & 0x7F) as u8,
+    ];
+
+    // The top entry is reserved
+    if entry == NO_CHANGE {
+        [0x7F, 0x7F, 0x7E]
+    } else {
+        entry
+    }
+}
+
+pub fn bulk_dump(name: &str, program: u8, frequencies: &[f64; 128]) -> Vec<u8> {
+    let mut data = vec![NON_REAL_TIME, 0x7F, MIDI_TUNING, BULK_DUMP, program & 0x7F];
+
+    data.extend(
+        name.bytes()
+            .filter(u8::is_ascii)
+            .chain(std::iter::repeat(b' '))
+            .take(NAME_LENGTH),
+    );
+
+    for &freq in frequencies {
+        data.extend(if freq > 0.0 { entry(freq) } else { NO_CHANGE });
+    }
+
+    let checksum = data.iter().fold(0, |sum, byte| sum ^ byte) & 0x7F;
+    data.push(checksum);
+
+    [vec![0xF0], data, vec![0xF7]].concat()
+}
+
+// Returns the notes the message retunes along with their new frequencies
+pub fn parse(data: &[u8]) -> Option<Vec<(u8, f64)>> {
+    let data = data.strip_prefix(&[0xF0]).unwrap_or(data);
+    let data = data.strip_suffix(&[0xF7]).unwrap_or(data);
+
+    let (&[universal, _device, MIDI_TUNING, format], rest) = data.split_first_chunk()? else {
+        return None;
+    };
+
+    match (universal, format) {
+        (NON_REAL_TIME, BULK_DUMP) => {
+            // Program number and name come before the entries
+            let entries = rest.get(1 + NAME_LENGTH..1 + NAME_LENGTH + 128 * 3)?;
+            let checksum = *rest.get(1 + NAME_LENGTH + 128 * 3)?;
+            let summed = &data[..4 + 1 + NAME_LENGTH + 128 * 3];
+
+            if checksum != summed.iter().fold(0, |sum, byte| sum ^ byte) & 0x7F {
+                eprintln!("WARNING: Bad checksum in MTS bulk dump");
+                return None;
+            }
+
+            Some(
+                entries
+                    .chunks_exact(3)
+                    .enumerate()
+                    .filter_map(|(note, entry)| note_entry(note as u8, entry))
+                    .collect(),
+            )
+        }
+        // Bank, program and count come before the entries
+        (REAL_TIME, NOTE_CHANGE) => note_changes(rest.get(1..)?),
+        (REAL_TIME | NON_REAL_TIME, BANK_NOTE_CHANGE) => note_changes(rest.get(2..)?),
+        _ => None,
+    }
+}
+
+fn note_changes(data: &[u8]) -> Option<Vec<(u8, f64)>> {
+    let (&count, entries) = data.split_first()?;
+
+    Some(
+        entries
+            .chunks_exact(4)
+            .take(count as usize)
+            .filter_map(|entry| note_entry(entry[0], &entry[1..]))
+            .collect(),
+    )
+}
+
+fn note_entry(note: u8, entry: &[u8]) -> Option<(u8, f64)> {
+    let entry: [u8; 3] = entry.try_into().ok()?;
+
+    (entry != NO_CHANGE && note < 128).then(|| (note, frequency(entry)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_count_semitones_from_a440() {
+        assert_eq!(frequency([69, 0, 0]), 440.0);
+        assert_eq!(frequency([57, 0, 0]), 220.0);
+
+        let quarter_tone = frequency([69, 0x40, 0]) / 440.0;
+        assert!((quarter_tone - 2.0_f64.powf(0.5 / 12.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn parses_single_note_changes() {
+        // Program 0 with two entries: note 60 becomes A4, note 61 is left alone
+        let message = [
+            &[REAL_TIME, 0x7F, MIDI_TUNING, NOTE_CHANGE][..],
+            &[0, 2],
+            &[60, 69, 0, 0],
+            &[61, 0x7F, 0x7F, 0x7F],
+        ]
+        .concat();
+        let framed = [&[0xF0], &message[..], &[0xF7]].concat();
+
+        assert_eq!(parse(&message), Some(vec![(60, 440.0)]));
+        assert_eq!(parse(&framed), Some(vec![(60, 440.0)]));
+    }
+
+    #[test]
+    fn parses_bank_note_changes() {
+        // Bank 1, program 0 with one entry
+        let message = [
+            &[REAL_TIME, 0x7F, MIDI_TUNING, BANK_NOTE_CHANGE][..],
+            &[1, 0, 1],
+            &[72, 57, 0, 0],
+        ]
+        .concat();
+
+        assert_eq!(parse(&message), Some(vec![(72, 220.0)]));
+
+        // The non-real-time variant only differs in when sounding notes follow
+        let message = [&[NON_REAL_TIME][..], &message[1..]].concat();
+
+        assert_eq!(parse(&message), Some(vec![(72, 220.0)]));
+    }
+
+    #[test]
+    fn ignores_other_messages() {
+        assert_eq!(parse(&[0xF0, 0x43, 0x10, 0x4C, 0xF7]), None);
+        assert_eq!(parse(&[REAL_TIME, 0x7F, MIDI_TUNING]), None);
+        assert_eq!(parse(&[REAL_TIME, 0x7F, MIDI_TUNING, NOTE_CHANGE]), None);
+    }
+
+    #[test]
+    fn bulk_dumps_round_trip() {
+        // Every other key tuned a third of a semitone sharp, the rest left alone
+        let frequencies = std::array::from_fn(|note| {
+            if note % 2 == 0 {
+                440.0 * 2.0_f64.powf((note as f64 + 1.0 / 3.0 - 69.0) / 12.0)
+            } else {
+                0.0
+            }
+        });
+
+        let notes = parse(&bulk_dump("round trip", 5, &frequencies)).unwrap();
+
+        assert_eq!(notes.len(), 64);
+
+        for (note, freq) in notes {
+            let cents = (freq / frequencies[note as usize]).log2() * 1200.0;
+
+            assert!(note % 2 == 0 && cents.abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn rejects_bulk_dumps_with_a_bad_checksum() {
+        let mut dump = bulk_dump("bad", 0, &[440.0; 128]);
+        let checksum = dump.len() - 2;
+        dump[checksum] ^= 1;
+
+        assert_eq!(parse(&dump), None);
+    }
+
+    #[test]
+    fn leaves_frequencies_outside_the_mts_range_alone() {
+        assert_eq!(entry(1.0), NO_CHANGE);
+        assert_eq!(entry(20000.0), NO_CHANGE);
+        assert_eq!(entry(440.0), [69, 0, 0]);
+    }
+}
@@ -1,22 +1,27 @@
 use alsa::direct::pcm::MmapPlayback;
 use alsa::pcm::{Frames, State};
-use alsa::{pcm, Direction, PollDescriptors, ValueOr, PCM};
-use anyhow::{anyhow, Result};
+use alsa::{Direction, PCM, PollDescriptors, ValueOr, pcm};
+use anyhow::{Result, anyhow};
 
-use crate::hw::{SAMPLE_RATE, SF};
-use synth::Synth;
+use crate::hw::{AudioSink, CHANNELS, SAMPLE_RATE, SF};
 
 const BUFFER_SIZE: Frames = 512;
 const PERIOD_SIZE: Frames = BUFFER_SIZE / 4;
 
 pub struct OutputDevice {
     device: PCM,
+    mmap: MmapPlayback<SF>,
+    position: u64,
 }
 
 impl OutputDevice {
     pub fn new(card: &str) -> Result<Self> {
+        let device = Self::open_audio_device(card)?;
+
         Ok(Self {
-            device: Self::open_audio_device(card)?,
+            mmap: device.direct_mmap_playback::<SF>()?,
+            device,
+            position: 0,
         })
     }
 
@@ -31,7 +36,7 @@ impl OutputDevice {
     pub fn open_audio_device(card: &str) -> Result<PCM> {
         fn set_hw_params(device: &PCM) -> Result<()> {
             let hw_params = pcm::HwParams::any(device)?;
-            hw_params.set_channels(2)?;
+            hw_params.set_channels(CHANNELS as u32)?;
             hw_params.set_rate(SAMPLE_RATE, ValueOr::Nearest)?;
             hw_params.set_format(pcm::Format::s16())?;
             hw_params.set_access(pcm::Access::MMapInterleaved)?;
@@ -64,16 +69,20 @@ impl OutputDevice {
     pub fn write_samples_direct(
         p: &PCM,
         mmap: &mut MmapPlayback<SF>,
-        mixer: &mut Synth,
-    ) -> Result<()> {
+        mixer: &mut impl Iterator<Item = SF>,
+        frames: u64,
+    ) -> Result<u64> {
+        let mut samples = mixer.take((frames as usize).saturating_mul(CHANNELS as usize));
+        let mut written = 0;
+
         loop {
             if mmap.avail() > 0 {
-                mmap.write(mixer);
+                written += mmap.write(&mut samples) as u64;
             }
 
             match mmap.status().state() {
                 State::Running => {
-                    return Ok(());
+                    return Ok(written);
                 }
                 State::Prepared => p.start()?,
                 State::XRun => {
@@ -86,3 +95,21 @@ impl OutputDevice {
         }
     }
 }
+
+impl AudioSink for OutputDevice {
+    fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn write(&mut self, mixer: &mut impl Iterator<Item = SF>, frames: u64) -> Result<u64> {
+        let written = Self::write_samples_direct(&self.device, &mut self.mmap, mixer, frames)?;
+
+        self.position += written;
+
+        Ok(written)
+    }
+
+    fn descriptors(&self) -> Result<Vec<alsa::poll::pollfd>> {
+        Ok(self.get()?)
+    }
+}
@@ -0,0 +1,207 @@
+use anyhow::{Result, anyhow};
+use hound::{SampleFormat, WavSpec, WavWriter};
+use midly::{MetaMessage, MidiMessage, Smf, Timing, TrackEventKind};
+use std::fs::File;
+use std::io::BufWriter;
+
+use crate::hw::{AudioSink, CHANNELS, EventSource, SAMPLE_RATE, SF};
+use crate::midi::MidiEvent;
+use crate::mts;
+
+fn convert_message(channel: u8, message: MidiMessage) -> Option<MidiEvent> {
+    match message {
+        MidiMessage::NoteOn { key, vel } => {
+            Some(MidiEvent::note_on(channel, key.as_int(), vel.as_int()))
+        }
+        MidiMessage::NoteOff { key, .. } => Some(MidiEvent::NoteOff {
+            channel,
+            note: key.as_int(),
+        }),
+        MidiMessage::Controller { controller, value } => Some(MidiEvent::Controller {
+            channel,
+            param: controller.as_int() as u32,
+            value: value.as_int() as i32,
+        }),
+        MidiMessage::PitchBend { bend } => Some(MidiEvent::PitchBend {
+            channel,
+            value: bend.as_int(),
+        }),
+        MidiMessage::ChannelAftertouch { vel } => Some(MidiEvent::ChannelPressure {
+            channel,
+            value: vel.as_int(),
+        }),
+        MidiMessage::Aftertouch { key, vel } => Some(MidiEvent::PolyPressure {
+            channel,
+            note: key.as_int(),
+            value: vel.as_int(),
+        }),
+        _ => None,
+    }
+}
+
+// Returns the events of all tracks merged and stamped with the frame they occur at
+fn read_events(filename: &str) -> Result<Vec<(u64, MidiEvent)>> {
+    let data = std::fs::read(filename)?;
+    let smf = Smf::parse(&data).map_err(|e| anyhow!("{filename}: {e}"))?;
+
+    let mut timeline = Vec::new();
+
+    for track in &smf.tracks {
+        let mut tick = 0_u64;
+
+        for event in track {
+            tick += event.delta.as_int() as u64;
+            timeline.push((tick, event.kind));
+        }
+    }
+
+    // Stable, so simultaneous events keep their track order
+    timeline.sort_by_key(|&(tick, _)| tick);
+
+    // Microseconds per quarter note, 120 BPM until told otherwise
+    let mut tempo = 500_000.0;
+    let mut last_tick = 0;
+    let mut seconds = 0.0;
+
+    let mut events = Vec::new();
+
+    for (tick, kind) in timeline {
+        seconds += match smf.header.timing {
+            Timing::Metrical(ticks_per_beat) => {
+                (tick - last_tick) as f64 * tempo / 1_000_000.0 / ticks_per_beat.as_int() as f64
+            }
+            Timing::Timecode(fps, subframes) => {
+                (tick - last_tick) as f64 / (fps.as_f32() as f64 * subframes as f64)
+            }
+        };
+        last_tick = tick;
+
+        let frame = (seconds * SAMPLE_RATE as f64).round() as u64;
+
+        match kind {
+            TrackEventKind::Midi { channel, message } => {
+                if let Some(event) = convert_message(channel.as_int(), message) {
+                    events.push((frame, event));
+                }
+            }
+            TrackEventKind::SysEx(data) => {
+                if let Some(notes) = mts::parse(data) {
+                    events.push((frame, MidiEvent::TuningChange { notes }));
+                }
+            }
+            TrackEventKind::Meta(MetaMessage::Tempo(t)) => tempo = t.as_int() as f64,
+            _ => {}
+        }
+    }
+
+    Ok(events)
+}
+
+pub struct SmfSource {
+    events: Vec<(u64, MidiEvent)>,
+    next: usize,
+    end: u64,
+}
+
+impl SmfSource {
+    pub fn open(filename: &str, tail: f64) -> Result<Self> {
+        let events = read_events(filename)?;
+        let end = events.last().map_or(0, |&(frame, _)| frame) + (tail * SAMPLE_RATE as f64) as u64;
+
+        Ok(Self {
+            events,
+            next: 0,
+            end,
+        })
+    }
+}
+
+impl EventSource for SmfSource {
+    fn read(&mut self, position: u64) -> Result<Option<MidiEvent>> {
+        match self.events.get(self.next) {
+            Some((frame, event)) if *frame <= position => {
+                self.next += 1;
+
+                Ok(Some(event.clone()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // The tail is scheduled like an event so the sink stops right at the end
+    fn next_event(&self) -> Option<u64> {
+        Some(
+            self.events
+                .get(self.next)
+                .map_or(self.end, |&(frame, _)| frame),
+        )
+    }
+
+    fn is_finished(&self, position: u64) -> bool {
+        self.next == self.events.len() && position >= self.end
+    }
+}
+
+pub struct WavSink {
+    writer: Option<WavWriter<BufWriter<File>>>,
+    float: bool,
+    position: u64,
+}
+
+impl WavSink {
+    const BLOCK_SIZE: u64 = 1024;
+
+    pub fn create(filename: &str, float: bool) -> Result<Self> {
+        let spec = WavSpec {
+            channels: CHANNELS,
+            sample_rate: SAMPLE_RATE,
+            bits_per_sample: if float { 32 } else { 16 },
+            sample_format: if float {
+                SampleFormat::Float
+            } else {
+                SampleFormat::Int
+            },
+        };
+
+        Ok(Self {
+            writer: Some(WavWriter::create(filename, spec)?),
+            float,
+            position: 0,
+        })
+    }
+}
+
+impl AudioSink for WavSink {
+    fn position(&self) -> u64 {
+        self.position
+    }
+
+    // Every voice repeats its sample for the second channel, just like the ALSA device sees it
+    fn write(&mut self, mixer: &mut impl Iterator<Item = SF>, frames: u64) -> Result<u64> {
+        let frames = frames.min(Self::BLOCK_SIZE);
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| anyhow!("WAV file already finalized"))?;
+
+        for sample in mixer.by_ref().take(frames as usize * CHANNELS as usize) {
+            if self.float {
+                writer.write_sample(sample as f32 / -(i16::MIN as f32))?;
+            } else {
+                writer.write_sample(sample)?;
+            }
+        }
+
+        self.position += frames;
+
+        Ok(frames)
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if let Some(writer) = self.writer.take() {
+            writer.finalize()?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,50 +1,125 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::cmp::Ordering;
+use std::fmt;
 use std::ops::{Div, Mul};
+use std::str::FromStr;
+use synth::TuningPreset;
 
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// Ratios are kept in lowest terms. Arithmetic stays exact until it involves cents or a ratio
+// outgrows u128, then it continues in cents
 #[derive(Clone, Copy, Debug)]
 pub enum Interval {
-    Ratio(usize, usize),
+    Ratio(u128, u128),
     Cents(f64),
 }
 
 impl Interval {
+    // None for a ratio that isn't positive
+    pub fn ratio(numerator: u128, denominator: u128) -> Option<Interval> {
+        if numerator == 0 || denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator);
+
+        Some(Interval::Ratio(numerator / divisor, denominator / divisor))
+    }
+
+    pub fn unison() -> Interval {
+        Interval::Ratio(1, 1)
+    }
+
+    pub fn cents(self) -> f64 {
+        match self {
+            Interval::Ratio(..) => self.to_f64().log2() * 1200.0,
+            Interval::Cents(c) => c,
+        }
+    }
+
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Interval::Ratio(n, m) => n as f64 / m as f64,
+            Interval::Cents(c) => 2.0_f64.powf(c / 1200.0),
+        }
+    }
+
     pub fn reciprocal(self) -> Interval {
         match self {
             Interval::Ratio(n, m) => Interval::Ratio(m, n),
             Interval::Cents(c) => Interval::Cents(-c),
         }
     }
+
+    pub fn pow(self, exponent: u32) -> Interval {
+        match self {
+            Interval::Ratio(n, m) => match (n.checked_pow(exponent), m.checked_pow(exponent)) {
+                (Some(n), Some(m)) => Interval::Ratio(n, m),
+                _ => Interval::Cents(self.cents() * exponent as f64),
+            },
+            Interval::Cents(c) => Interval::Cents(c * exponent as f64),
+        }
+    }
+
+    pub fn checked_mul(self, other: Interval) -> Option<Interval> {
+        let (Interval::Ratio(a, b), Interval::Ratio(c, d)) = (self, other) else {
+            return None;
+        };
+
+        // Cancelling across first keeps the products as small as they can be
+        let (ad, cb) = (gcd(a, d), gcd(c, b));
+
+        Interval::ratio((a / ad).checked_mul(c / cb)?, (b / cb).checked_mul(d / ad)?)
+    }
 }
 
-impl Mul for Interval {
-    type Output = Self;
+impl PartialEq for Interval {
+    fn eq(&self, other: &Interval) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
 
-    fn mul(self, other: Interval) -> Interval {
-        match self {
-            Interval::Ratio(a, b) => match other {
-                Interval::Ratio(c, d) => Interval::Ratio(a * c, b * d),
-                Interval::Cents(c) => {
-                    let cents = (a as f64 / b as f64).log2() * 1200.0;
+// Intervals compare by size, ratios exactly
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        if let (&Interval::Ratio(a, b), &Interval::Ratio(c, d)) = (self, other)
+            && let (Some(ad), Some(cb)) = (a.checked_mul(d), c.checked_mul(b))
+        {
+            return Some(ad.cmp(&cb));
+        }
 
-                    Interval::Cents(c + cents)
-                }
-            },
-            Interval::Cents(x) => match other {
-                Interval::Ratio(n, m) => {
-                    let cents = (n as f64 / m as f64).log2() * 1200.0;
+        self.cents().partial_cmp(&other.cents())
+    }
+}
 
-                    Interval::Cents(x + cents)
-                }
-                Interval::Cents(y) => Interval::Cents(x + y),
-            },
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Interval::Ratio(n, m) => write!(f, "{n}/{m}"),
+            Interval::Cents(c) => write!(f, "{c:.6}"),
         }
     }
 }
 
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, other: Interval) -> Interval {
+        self.checked_mul(other)
+            .unwrap_or_else(|| Interval::Cents(self.cents() + other.cents()))
+    }
+}
+
 impl Div for Interval {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: Interval) -> Interval {
         self * other.reciprocal()
     }
@@ -54,22 +129,20 @@ impl Mul<Interval> for f64 {
     type Output = Self;
 
     fn mul(self, other: Interval) -> f64 {
-        match other {
-            Interval::Ratio(n, m) => self * (n as f64 / m as f64),
-            Interval::Cents(c) => self * 2.0_f64.powf(c / 1200.0),
-        }
+        self * other.to_f64()
     }
 }
 
 impl Div<Interval> for f64 {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: Interval) -> f64 {
         self * other.reciprocal()
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Scale {
     intervals: Vec<Interval>,
 }
@@ -83,242 +156,575 @@ impl Scale {
         self.intervals.len()
     }
 
-    // pub fn mode(&self, offset: usize) -> Vec<Interval> {
-    //     if offset > self.size() {
-    //         panic!("Oops");
-    //     }
-    //
-    //     let mut intervals = self.intervals.clone();
-    //
-    //     intervals.rotate_right(offset);
-    //
-    //     if offset > 0 {
-    //         intervals[0..offset]
-    //             .iter_mut()
-    //             .for_each(|v| *v = *v / Interval::Ratio(2, 1));
-    //     }
-    //
-    //     intervals
-    // }
-
-    pub fn interval_at(&self, i: isize) -> Interval {
-        self.intervals[i.rem_euclid(self.intervals.len() as isize) as usize]
-    }
-
-    // pub fn frequencies(&self, base_freq: f64, base_note: usize) -> Vec<f64> {
-    //     if base_note > self.size() {
-    //         panic!("Oops");
-    //     }
-    //
-    //     let mode = self.mode(base_note);
-    //
-    //     dbg!(&mode);
-    //
-    //     // TODO
-    //     mode.iter().map(|&interval| base_freq * interval).collect()
-    // }
-
-    // TODO non-octave
-    pub fn frequency_at(&self, base_freq: f64, idx: isize) -> f64 {
-        let size = self.size();
-        // let octaves = (idx.abs() as usize).saturating_sub(1) / size;
-        // let octaves = (idx.abs() as usize) / size;
-        // let i = idx.rem_euclid(size as isize) as usize;
-
-        let interval = self.interval_at(idx);
-
-        if idx.signum() < 0 {
-            let octaves = (idx.abs() as usize).saturating_sub(1) / size;
-            let octaves = octaves + 1;
-
-            dbg!(
-                idx,
-                octaves,
-                interval / Interval::Ratio(2_usize.pow(octaves as u32), 1)
-            );
-            base_freq * (interval / Interval::Ratio(2_usize.pow(octaves as u32), 1))
+    // The last interval of a Scala file is the one the scale repeats at, not always 2/1
+    pub fn period(&self) -> Interval {
+        self.intervals[self.size() - 1]
+    }
+
+    // The scale rotated so that `offset` becomes the 1/1, the period stays the same
+    pub fn mode(&self, offset: usize) -> Scale {
+        let offset = (offset % self.size()) as isize;
+        let tonic = self.degree(offset);
+
+        Scale::new(
+            (1..=self.size() as isize)
+                .map(|degree| self.degree(offset + degree) / tonic)
+                .collect(),
+        )
+    }
+
+    // Degree 0 is the 1/1, the last interval of the scale closes each period
+    pub fn degree(&self, degree: isize) -> Interval {
+        let size = self.size() as isize;
+        let periods = degree.div_euclid(size);
+        let index = degree.rem_euclid(size) as usize;
+
+        let interval = if index == 0 {
+            Interval::unison()
+        } else {
+            self.intervals[index - 1]
+        };
+
+        let period = self.period().pow(periods.unsigned_abs() as u32);
+
+        if periods < 0 {
+            interval / period
         } else {
-            let octaves = (idx.abs() as usize) / size;
-            dbg!(
-                idx,
-                octaves,
-                interval * Interval::Ratio(2_usize.pow(octaves as u32), 1)
-            );
-            base_freq * (interval * Interval::Ratio(2_usize.pow(octaves as u32), 1))
+            interval * period
         }
     }
 }
 
-// TODO ability to specify base frequency/note for cyclical scales
-pub fn parse_scala_file(filename: &str) -> Scale {
-    if let Ok(file) = File::open(filename) {
-        let reader = BufReader::new(file);
+// Lines and columns count from 1, the column is missing when the file ended early
+#[derive(Debug)]
+pub struct ScalaError {
+    filename: String,
+    line: Option<usize>,
+    column: Option<usize>,
+    reason: String,
+}
 
-        // TODO
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.map(|x| x.trim().to_string()))
-            .collect::<Result<_, _>>()
-            .unwrap();
+impl ScalaError {
+    fn new(filename: &str, reason: impl Into<String>) -> Self {
+        Self {
+            filename: filename.to_string(),
+            line: None,
+            column: None,
+            reason: reason.into(),
+        }
+    }
+}
 
-        let mut idx = 0;
+impl fmt::Display for ScalaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (filename, reason) = (&self.filename, &self.reason);
 
-        while lines[idx].starts_with('!') {
-            idx += 1;
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{filename}:{line}:{column}: {reason}"),
+            (Some(line), None) => write!(f, "{filename}:{line}: {reason}"),
+            _ => write!(f, "{filename}: {reason}"),
         }
+    }
+}
 
-        idx += 1;
+impl std::error::Error for ScalaError {}
 
-        while lines[idx].starts_with('!') {
-            idx += 1;
+// Yields the lines that aren't comments, keeping track of where the last one starts
+struct Lines<'a> {
+    filename: &'a str,
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    last: usize,
+    column: Option<usize>,
+}
+
+impl<'a> Lines<'a> {
+    fn new(filename: &'a str, text: &'a str) -> Self {
+        Self {
+            filename,
+            lines: text.lines().enumerate(),
+            last: 0,
+            column: None,
         }
+    }
 
-        let count = lines[idx].parse::<usize>().unwrap();
-        // if count != 127 {
-        //     panic!("Not implemented");
-        // }
+    // Points at the first word of the last line
+    fn error(&self, reason: impl Into<String>) -> ScalaError {
+        ScalaError {
+            line: Some(self.last).filter(|&line| line > 0),
+            column: self.column,
+            ..ScalaError::new(self.filename, reason)
+        }
+    }
+
+    fn next(&mut self, what: &str) -> Result<&'a str, ScalaError> {
+        for (i, line) in self.lines.by_ref() {
+            self.last = i + 1;
 
-        idx += 1;
+            if !line.trim_start().starts_with('!') {
+                self.column = Some(line.chars().take_while(|c| c.is_whitespace()).count() + 1);
 
-        while lines[idx].starts_with('!') {
-            idx += 1;
+                return Ok(line.trim());
+            }
         }
 
-        let mut intervals = Vec::with_capacity(count);
+        self.column = None;
 
-        // intervals.push(Interval::Ratio(1, 1));
+        Err(self.error(format!("Expected {what}, found the end of the file")))
+    }
 
-        for i in 0..count {
-            let data = &lines[idx + i];
+    // Anything after the first word is a comment
+    fn value<T: FromStr>(&mut self, what: &str) -> Result<T, ScalaError> {
+        let line = self.next(what)?;
+        let word = line.split_whitespace().next().unwrap_or_default();
 
-            dbg!(&data);
+        word.parse()
+            .map_err(|_| self.error(format!("Expected {what}, found \"{line}\"")))
+    }
+}
 
-            if data.starts_with('!') {
-                continue;
-            } else if data.contains('.') {
-                let cents = data.parse::<f64>().unwrap();
+fn read(filename: &str) -> Result<String, ScalaError> {
+    std::fs::read_to_string(filename).map_err(|e| ScalaError::new(filename, e.to_string()))
+}
 
-                intervals.push(Interval::Cents(cents));
-            } else {
-                if data.contains('/') {
-                    // TODO Need to ignore junk that comes after the interval
-                    let ratio = data.split('/').collect::<Vec<&str>>();
-                    let numerator = ratio[0].parse::<usize>().unwrap();
-                    let denominator = ratio[1].parse::<usize>().unwrap();
+fn parse_interval(word: &str) -> Option<Interval> {
+    if word.contains('.') {
+        return word.parse().ok().map(Interval::Cents);
+    }
 
-                    intervals.push(Interval::Ratio(numerator, denominator));
-                } else {
-                    let n = data.parse::<usize>().unwrap();
+    let (numerator, denominator) = word.split_once('/').unwrap_or((word, "1"));
+    let numerator = numerator.parse().ok()?;
+    let denominator = denominator.parse().ok()?;
 
-                    intervals.push(Interval::Ratio(n, 1));
-                }
-            }
+    Interval::ratio(numerator, denominator)
+}
+
+// TODO ability to specify base frequency/note for cyclical scales
+pub fn parse_scala_file(filename: &str) -> Result<Scale, ScalaError> {
+    parse_scala(filename, &read(filename)?)
+}
+
+// `filename` only names the source in errors
+fn parse_scala(filename: &str, text: &str) -> Result<Scale, ScalaError> {
+    let mut lines = Lines::new(filename, text);
+
+    // The description may be empty
+    lines.next("a description")?;
+
+    let count: usize = lines.value("the number of notes")?;
+
+    if count == 0 {
+        return Err(lines.error("A scale needs at least one note"));
+    }
+
+    let intervals = (0..count)
+        .map(|i| {
+            let what = format!("note {} of {count}", i + 1);
+            let line = lines.next(&what)?;
+            let word = line.split_whitespace().next().unwrap_or_default();
+
+            parse_interval(word)
+                .ok_or_else(|| lines.error(format!("Expected {what}, found \"{line}\"")))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Scale::new(intervals))
+}
+
+// Which scale degree each key plays, as described by a Scala .kbm file
+#[derive(Clone, Debug)]
+pub struct KeyboardMapping {
+    first_note: u8,
+    last_note: u8,
+    middle_note: u8,
+    reference_note: u8,
+    reference_freq: f64,
+    octave_degree: usize,
+    // Empty for a linear mapping, otherwise one entry per key of the pattern
+    keys: Vec<Option<usize>>,
+}
+
+impl KeyboardMapping {
+    // Consecutive keys play consecutive degrees, with the 1/1 on `base_note`
+    pub fn linear(base_note: u8, base_freq: f64) -> Self {
+        Self {
+            first_note: 0,
+            last_note: 127,
+            middle_note: base_note,
+            reference_note: base_note,
+            reference_freq: base_freq,
+            octave_degree: 0,
+            keys: Vec::new(),
         }
+    }
+
+    // The scale degree a key plays and how many formal octaves above the pattern it lies.
+    // Linear mappings simply count degrees, the scale repeats at its own period
+    pub fn degree_of(&self, note: u8) -> Option<(isize, isize)> {
+        let offset = note as isize - self.middle_note as isize;
+
+        if self.keys.is_empty() {
+            return Some((offset, 0));
+        }
+
+        let size = self.keys.len() as isize;
 
-        Scale::new(intervals)
-    } else {
-        panic!("WARNING ")
+        self.keys[offset.rem_euclid(size) as usize]
+            .map(|degree| (degree as isize, offset.div_euclid(size)))
+    }
+
+    // Keys past the pattern repeat it at the formal octave, whatever the scale repeats at
+    pub fn interval_of(&self, note: u8, scale: &Scale) -> Option<Interval> {
+        let (degree, octaves) = self.degree_of(note)?;
+        let octave = match self.octave_degree {
+            0 => scale.period(),
+            degree => scale.degree(degree as isize),
+        };
+        let octaves_up = octave.pow(octaves.unsigned_abs() as u32);
+
+        Some(if octaves < 0 {
+            scale.degree(degree) / octaves_up
+        } else {
+            scale.degree(degree) * octaves_up
+        })
     }
 }
 
-pub fn scale_to_tuning(scale: Scale, base_freq: f64, base_note: u8) -> [f64; 128] {
-    let mut tuning = [0.0; 128];
-    tuning[base_note as usize] = base_freq;
+pub fn parse_keyboard_mapping_file(filename: &str) -> Result<KeyboardMapping, ScalaError> {
+    parse_keyboard_mapping(filename, &read(filename)?)
+}
 
-    for i in base_note as usize + 1..128 {
-        let idx = i as isize - base_note as isize - 1;
-        tuning[i] = scale.frequency_at(base_freq, idx);
+fn parse_keyboard_mapping(filename: &str, text: &str) -> Result<KeyboardMapping, ScalaError> {
+    let mut lines = Lines::new(filename, text);
+
+    let size: usize = lines.value("the map size")?;
+    let first_note = lines.value("the first note")?;
+    let last_note = lines.value("the last note")?;
+    let middle_note = lines.value("the middle note")?;
+    let reference_note = lines.value("the reference note")?;
+    let reference_freq = lines.value("the reference frequency")?;
+    let octave_degree = lines.value("the formal octave degree")?;
+
+    // Keys missing at the end of the mapping are unmapped
+    let mut keys = Vec::with_capacity(size);
+
+    for _ in 0..size {
+        match lines.value::<String>("a key") {
+            Ok(key) if key == "x" => keys.push(None),
+            Ok(key) => keys.push(Some(key.parse().map_err(|_| {
+                lines.error(format!("Expected a scale degree or x, found \"{key}\""))
+            })?)),
+            Err(_) => keys.push(None),
+        }
     }
 
-    for i in 0..base_note as usize {
-        let idx = -(i as isize + 1);
-        tuning[base_note as usize - i] = scale.frequency_at(base_freq, idx);
+    let mapping = KeyboardMapping {
+        first_note,
+        last_note,
+        middle_note,
+        reference_note,
+        reference_freq,
+        octave_degree,
+        keys,
+    };
+
+    // Nothing else says which degree the reference frequency belongs to
+    if mapping.degree_of(reference_note).is_none() {
+        return Err(ScalaError::new(
+            filename,
+            format!("The reference note {reference_note} is unmapped"),
+        ));
+    }
+
+    Ok(mapping)
+}
+
+// Unmapped keys get a frequency of zero
+pub fn scale_to_tuning(scale: &Scale, mapping: &KeyboardMapping) -> [f64; 128] {
+    let mut tuning = [0.0; 128];
+
+    // Parsing makes sure the reference note is mapped
+    let Some(reference) = mapping.interval_of(mapping.reference_note, scale) else {
+        return tuning;
+    };
+    let middle_freq = mapping.reference_freq / reference;
+
+    for note in mapping.first_note..=mapping.last_note.min(127) {
+        if let Some(interval) = mapping.interval_of(note, scale) {
+            tuning[note as usize] = middle_freq * interval;
+        }
     }
 
     tuning
 }
 
-// pub fn parse_scala_file(filename: &str) -> [f64; 128] {
-//     if let Ok(file) = File::open(filename) {
-//         let reader = BufReader::new(file);
-//
-//         // TODO
-//         let lines: Vec<String> = reader
-//             .lines()
-//             .map(|x| x.map(|x| x.trim().to_string()))
-//             .collect::<Result<_, _>>()
-//             .unwrap();
-//
-//         let mut idx = 0;
-//
-//         while lines[idx].starts_with('!') {
-//             idx += 1;
-//         }
-//
-//         idx += 1;
-//
-//         while lines[idx].starts_with('!') {
-//             idx += 1;
-//         }
-//
-//         let count = lines[idx].parse::<usize>().unwrap();
-//         // if count != 127 {
-//         //     panic!("Not implemented");
-//         // }
-//
-//         idx += 1;
-//
-//         while lines[idx].starts_with('!') {
-//             idx += 1;
-//         }
-//
-//         let base_freq = 8.25;
-//
-//         let mut frequencies = [base_freq; 128];
-//
-//         let start = idx;
-//
-//         for i in 1..128 {
-//             let data = &lines[idx];
-//
-//             if data.starts_with('!') {
-//                 continue;
-//             } else if data.contains('.') {
-//                 let cents = data.parse::<f64>().unwrap();
-//
-//                 let octave = ((i - 1) / count) as f64;
-//
-//                 frequencies[i] = base_freq * 2.0_f64.powf((cents + octave * 1200.0) / 1200.0);
-//                 // frequencies[idx] = base_freq * 2.0_f64.powf(cents / 1200.0);
-//             } else {
-//                 let interval = if data.contains('/') {
-//                     let ratio = data.split('/').collect::<Vec<&str>>();
-//                     let numerator = ratio[0].parse::<f64>().unwrap();
-//                     let denominator = ratio[1].parse::<f64>().unwrap();
-//
-//                     numerator / denominator
-//                 } else {
-//                     data.parse::<f64>().unwrap()
-//                 };
-//
-//                 let octave = ((i - 1) / count) as f64;
-//
-//                 frequencies[i] = base_freq * 2.0_f64.powf(octave) * interval;
-//                 // frequencies[idx] = base_freq * interval;
-//             }
-//
-//             idx = if idx - start + 1 == count {
-//                 start
-//             } else {
-//                 idx + 1
-//             };
-//
-//             // dbg!(idx);
-//         }
-//         // dbg!(&frequencies);
-//
-//         frequencies
-//     } else {
-//         panic!("WARNING ")
-//     }
-// }
+// A scale laid out on the keyboard in one of its modes
+#[derive(Clone, Debug)]
+pub struct Tuning {
+    pub name: String,
+    pub scale: Scale,
+    pub mapping: KeyboardMapping,
+    pub mode: usize,
+}
+
+impl Tuning {
+    pub fn frequencies(&self) -> [f64; 128] {
+        scale_to_tuning(&self.scale.mode(self.mode), &self.mapping)
+    }
+
+    pub fn preset(&self) -> TuningPreset {
+        TuningPreset {
+            name: self.name.clone(),
+            frequencies: self.frequencies(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PENTATONIC: &str = "! pentatonic.scl
+!
+Major pentatonic with a meantone fifth
+ 5
+!
+ 9/8
+ 5/4
+ 696.578 ! quarter-comma fifth
+ 5/3
+ 2/1
+";
+
+    // The pentatonic on C, D, E, G and A with A4 at 440 Hz
+    const WHITE_KEYS: &str = "! white.kbm
+ 12
+ 0
+ 127
+ 60
+ 69
+ 440.0
+ 5
+! Mapping
+ 0
+ x
+ 1
+ x
+ 2
+ x
+ x
+ 3
+ x
+ 4
+";
+
+    #[test]
+    fn parses_ratios_and_cents() {
+        let scale = parse_scala("pentatonic.scl", PENTATONIC).unwrap();
+
+        assert_eq!(scale.size(), 5);
+        assert_eq!(scale.degree(1), Interval::Ratio(9, 8));
+        assert!((scale.degree(3).cents() - 696.578).abs() < 1e-9);
+        assert_eq!(scale.degree(5), Interval::Ratio(2, 1));
+        assert_eq!(scale.degree(-1), Interval::Ratio(5, 6));
+    }
+
+    #[test]
+    fn parses_keyboard_mappings() {
+        let scale = parse_scala("pentatonic.scl", PENTATONIC).unwrap();
+        let mapping = parse_keyboard_mapping("white.kbm", WHITE_KEYS).unwrap();
+
+        assert_eq!(mapping.degree_of(69), Some((4, 0)));
+        assert_eq!(mapping.degree_of(61), None);
+        assert_eq!(mapping.degree_of(72), Some((0, 1)));
+        assert_eq!(mapping.degree_of(57), Some((4, -1)));
+
+        let tuning = scale_to_tuning(&scale, &mapping);
+
+        assert_eq!(tuning[60], 264.0);
+        assert_eq!(tuning[61], 0.0);
+        assert_eq!(tuning[62], 297.0);
+        assert_eq!(tuning[69], 440.0);
+        assert_eq!(tuning[72], 528.0);
+        assert_eq!(tuning[57], 220.0);
+    }
+
+    #[test]
+    fn unlisted_keys_are_unmapped() {
+        let scale = parse_scala("pentatonic.scl", PENTATONIC).unwrap();
+        let mapping =
+            parse_keyboard_mapping("short.kbm", "2\n0\n127\n60\n60\n261.6\n0\n0\n").unwrap();
+
+        assert_eq!(mapping.degree_of(60), Some((0, 0)));
+        assert_eq!(mapping.degree_of(61), None);
+        assert_eq!(mapping.degree_of(62), Some((0, 1)));
+        assert_eq!(scale_to_tuning(&scale, &mapping)[62], 523.2);
+    }
+
+    #[test]
+    fn patterns_repeat_at_the_formal_octave() {
+        let scale = parse_scala("pentatonic.scl", PENTATONIC).unwrap();
+        // Two keys per 5/4, the third degree of the scale
+        let mapping = parse_keyboard_mapping(
+            "thirds.kbm",
+            "2
+0
+127
+60
+60
+100.0
+2
+0
+1
+",
+        )
+        .unwrap();
+
+        let tuning = scale_to_tuning(&scale, &mapping);
+
+        assert_eq!(tuning[60], 100.0);
+        assert_eq!(tuning[61], 112.5);
+        assert_eq!(tuning[62], 125.0);
+        assert_eq!(tuning[63], 140.625);
+        assert_eq!(tuning[58], 80.0);
+    }
+
+    #[test]
+    fn rejects_unmapped_reference_notes() {
+        assert_eq!(
+            error(parse_keyboard_mapping(
+                "white.kbm",
+                &WHITE_KEYS.replace(" 69\n", " 61\n")
+            )),
+            "white.kbm: The reference note 61 is unmapped"
+        );
+    }
+
+    fn error(parsed: Result<impl fmt::Debug, ScalaError>) -> String {
+        parsed.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn reports_the_line_and_column_of_bad_notes() {
+        let text = "! bad.scl\nBad\n 3\n 9/8\n   foo ! not a note\n 2/1\n";
+
+        assert_eq!(
+            error(parse_scala("bad.scl", text)),
+            "bad.scl:5:4: Expected note 2 of 3, found \"foo ! not a note\""
+        );
+    }
+
+    #[test]
+    fn skips_indented_comments() {
+        let scale = parse_scala(
+            "indented.scl",
+            "Indented\n  ! count\n 1\n\t! the octave\n 2/1\n",
+        );
+
+        assert!(matches!(scale.unwrap().period(), Interval::Ratio(2, 1)));
+    }
+
+    #[test]
+    fn reports_scales_that_end_early() {
+        assert_eq!(
+            error(parse_scala("short.scl", "Short\n 3\n 9/8\n")),
+            "short.scl:3: Expected note 2 of 3, found the end of the file"
+        );
+        assert_eq!(
+            error(parse_scala("empty.scl", "")),
+            "empty.scl: Expected a description, found the end of the file"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_and_non_positive_scales() {
+        assert_eq!(
+            error(parse_scala("none.scl", "None\n0\n")),
+            "none.scl:2:1: A scale needs at least one note"
+        );
+        assert_eq!(
+            error(parse_scala("zero.scl", "Zero\n1\n\t0/1\n")),
+            "zero.scl:3:2: Expected note 1 of 1, found \"0/1\""
+        );
+    }
+
+    #[test]
+    fn reports_bad_keyboard_mappings() {
+        assert_eq!(
+            error(parse_keyboard_mapping("bad.kbm", "1\n0\n127\n60\n69\nA4\n")),
+            "bad.kbm:6:1: Expected the reference frequency, found \"A4\""
+        );
+        assert_eq!(
+            error(parse_keyboard_mapping(
+                "bad.kbm",
+                "1\n0\n127\n60\n69\n440\n0\n y\n"
+            )),
+            "bad.kbm:8:2: Expected a scale degree or x, found \"y\""
+        );
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        assert!(error(parse_scala_file("/nonexistent.scl")).starts_with("/nonexistent.scl: "));
+    }
+
+    #[test]
+    fn ratios_are_kept_in_lowest_terms() {
+        assert!(matches!(Interval::ratio(6, 4), Some(Interval::Ratio(3, 2))));
+        assert!(Interval::ratio(0, 4).is_none());
+        assert!(Interval::ratio(4, 0).is_none());
+
+        let fourth = Interval::Ratio(4, 3);
+
+        assert!(matches!(
+            Interval::Ratio(3, 2) * fourth,
+            Interval::Ratio(2, 1)
+        ));
+        assert!(matches!(
+            Interval::Ratio(3, 2) / fourth,
+            Interval::Ratio(9, 8)
+        ));
+        assert_eq!(Interval::Ratio(9, 8).to_string(), "9/8");
+    }
+
+    #[test]
+    fn overflowing_ratios_continue_in_cents() {
+        let fifth = Interval::Ratio(3, 2);
+        let stacked = fifth.pow(100);
+
+        assert!(matches!(stacked, Interval::Cents(_)));
+        assert!((stacked.cents() - 100.0 * fifth.cents()).abs() < 1e-6);
+
+        let large = Interval::Ratio(u128::MAX - 1, 3);
+
+        assert!(matches!(large * large, Interval::Cents(_)));
+        assert!(large > fifth && fifth < Interval::Cents(702.0));
+    }
+
+    #[test]
+    fn repeats_at_large_periods_far_from_the_base_note() {
+        let tritave = parse_scala("tritave.scl", "Tritave\n 2\n 9/4\n 3/1\n").unwrap();
+        let highest = tritave.degree(254);
+
+        assert!(matches!(highest, Interval::Cents(_)));
+        assert!((highest.cents() - 127.0 * Interval::Ratio(3, 1).cents()).abs() < 1e-6);
+        assert!(matches!(tritave.degree(-3), Interval::Ratio(1, 4)));
+    }
+
+    #[test]
+    fn modes_rotate_the_scale_onto_another_degree() {
+        let scale = parse_scala("pentatonic.scl", PENTATONIC).unwrap();
+
+        // Starting on 9/8 gives 10/9, 696.578 - 203.910 cents, 40/27, 16/9 and 2/1
+        let mode = scale.mode(1);
+
+        assert!(matches!(mode.degree(1), Interval::Ratio(10, 9)));
+        assert!((mode.degree(2).cents() - (696.578 - Interval::Ratio(9, 8).cents())).abs() < 1e-9);
+        assert!(matches!(mode.degree(3), Interval::Ratio(40, 27)));
+        assert!(matches!(mode.degree(4), Interval::Ratio(16, 9)));
+        assert!(matches!(mode.period(), Interval::Ratio(2, 1)));
+
+        // Offsets wrap around the period
+        assert!(matches!(scale.mode(6).degree(1), Interval::Ratio(10, 9)));
+        assert!(matches!(scale.mode(5).degree(4), Interval::Ratio(5, 3)));
+    }
+}
@@ -0,0 +1,194 @@
+use anyhow::Result;
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+use synth::SynthSetting;
+
+use crate::scala::Tuning;
+
+const INTERVAL: Duration = Duration::from_secs(1);
+
+// Directories count as modified when anything directly inside them is
+fn modified(path: &Path) -> Option<SystemTime> {
+    let metadata = fs::metadata(path).ok()?;
+    let mut latest = metadata.modified().ok()?;
+
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).ok()?.flatten() {
+            if let Ok(time) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                latest = latest.max(time);
+            }
+        }
+    }
+
+    Some(latest)
+}
+
+// The path doesn't need to exist yet, creating it counts as a change
+struct Watch {
+    filename: String,
+    stamp: Option<SystemTime>,
+}
+
+impl Watch {
+    fn new(filename: String) -> Self {
+        let stamp = modified(Path::new(&filename));
+
+        Self { filename, stamp }
+    }
+
+    // Removing the file isn't a change there is anything to load from
+    fn changed(&mut self) -> bool {
+        let stamp = modified(Path::new(&self.filename));
+        let changed = stamp.is_some() && stamp != self.stamp;
+        self.stamp = stamp;
+
+        changed
+    }
+}
+
+// The file that changed along with what it holds now
+pub enum Change {
+    Settings(String, Result<Box<[SynthSetting; 8]>>),
+    Tunings(String, Result<Vec<Tuning>>),
+}
+
+// Polls modification times, there is no need to be quicker than a musician with an editor.
+// Files are loaded on the watching thread so the audio loop only picks up the results
+pub struct Reloader {
+    changes: Receiver<Change>,
+    // Dropping it wakes the watching thread up to stop
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Reloader {
+    pub fn spawn(
+        settings_filename: Option<String>,
+        tuning_preset_filename: Option<String>,
+        base_freq: f64,
+        base_note: u8,
+    ) -> Self {
+        Self::with_interval(
+            settings_filename,
+            tuning_preset_filename,
+            base_freq,
+            base_note,
+            INTERVAL,
+        )
+    }
+
+    fn with_interval(
+        settings_filename: Option<String>,
+        tuning_preset_filename: Option<String>,
+        base_freq: f64,
+        base_note: u8,
+        interval: Duration,
+    ) -> Self {
+        let (sender, changes) = mpsc::channel();
+        let (stop, stopped) = mpsc::channel();
+        let settings = settings_filename.map(Watch::new);
+        let tunings = tuning_preset_filename.map(Watch::new);
+
+        let thread = thread::spawn(move || {
+            watch(
+                sender, stopped, interval, settings, tunings, base_freq, base_note,
+            )
+        });
+
+        Self {
+            changes,
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+
+    // Never blocks
+    pub fn changes(&self) -> Vec<Change> {
+        self.changes.try_iter().collect()
+    }
+}
+
+impl Drop for Reloader {
+    fn drop(&mut self) {
+        self.stop.take();
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+// Runs until the reloader is dropped or stops listening
+fn watch(
+    sender: Sender<Change>,
+    stopped: Receiver<()>,
+    interval: Duration,
+    mut settings: Option<Watch>,
+    mut tunings: Option<Watch>,
+    base_freq: f64,
+    base_note: u8,
+) {
+    while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+        if let Some(watch) = settings.as_mut()
+            && watch.changed()
+        {
+            let loaded = crate::read_settings_file(&watch.filename).map(Box::new);
+
+            if sender
+                .send(Change::Settings(watch.filename.clone(), loaded))
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        if let Some(watch) = tunings.as_mut()
+            && watch.changed()
+        {
+            let loaded = crate::load_tunings(&watch.filename, base_freq, base_note);
+
+            if sender
+                .send(Change::Tunings(watch.filename.clone(), loaded))
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_settings_files_created_after_startup() {
+        let filename = std::env::temp_dir().join(format!("instr-{}.json", std::process::id()));
+        let filename = filename.to_string_lossy().into_owned();
+        let mut reloader = Reloader::with_interval(
+            Some(filename.clone()),
+            None,
+            440.0,
+            69,
+            Duration::from_millis(10),
+        );
+
+        fs::write(
+            &filename,
+            serde_json::to_string(&[SynthSetting::default(); 8]).unwrap(),
+        )
+        .unwrap();
+
+        let change = reloader.changes.recv_timeout(Duration::from_secs(10));
+        fs::remove_file(&filename).unwrap();
+
+        assert!(matches!(change, Ok(Change::Settings(changed, Ok(_))) if changed == filename));
+
+        // Hanging up stops the watching thread
+        reloader.stop.take();
+        reloader.thread.take().unwrap().join().unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+// Generates the dynamic tuning tables, one for every subset of the primes
+use std::env;
+use std::fs::File;
+use std::io::{BufWriter, Result, Write};
+use std::path::Path;
+
+#[path = "src/intervals.rs"]
+mod intervals;
+#[allow(dead_code)]
+#[path = "src/primes.rs"]
+mod primes;
+
+// Shorthands for the tables the adaptive tuning fits to
+const LIMITS: &str = "pub const PYTHAGOREAN: Tuning = Tuning::Pf3;
+pub const FIVE_LIMIT: Tuning = Tuning::Pf3_5;
+pub const SEVEN_LIMIT: Tuning = Tuning::Pf3_5_7;
+pub const ELEVEN_LIMIT: Tuning = Tuning::Pf3_5_7_11;
+pub const THIRTEEN_LIMIT: Tuning = Tuning::Pf3_5_7_11_13;
+pub const SEVENTEEN_LIMIT: Tuning = Tuning::Pf17_3_5_7_11_13;";
+
+// Variants name the tables by their primes, in index order. The primes from 17 up come
+// first, as they did when 17 was added to the hand-written names
+fn tuning_name(index: usize) -> String {
+    let (high, low): (Vec<u32>, Vec<u32>) = primes::PRIMES
+        .iter()
+        .enumerate()
+        .filter(|&(bit, _)| index & 1 << bit != 0)
+        .map(|(_, &prime)| prime)
+        .partition(|&prime| prime >= 17);
+    let primes: Vec<String> = high.iter().chain(&low).map(u32::to_string).collect();
+
+    if primes.is_empty() {
+        "Pf_".to_string()
+    } else {
+        format!("Pf{}", primes.join("_"))
+    }
+}
+
+fn emit_tunings(file: &mut impl Write, count: usize) -> Result<()> {
+    writeln!(file, "#[repr(usize)]")?;
+    writeln!(file, "pub enum Tuning {{")?;
+
+    for index in 0..count {
+        writeln!(file, "    {},", tuning_name(index))?;
+    }
+
+    writeln!(file, "}}")?;
+    writeln!(file)?;
+    writeln!(file, "{LIMITS}")
+}
+
+fn emit_table(file: &mut impl Write, table: &[f64; 12]) -> Result<()> {
+    writeln!(file, "[")?;
+
+    for octave in 0..8 {
+        for interval in table {
+            writeln!(file, "\t{}f64,", interval * f64::powi(2.0, octave))?;
+        }
+    }
+
+    writeln!(file, "]")
+}
+
+fn main() -> Result<()> {
+    println!("cargo:rerun-if-changed=src/intervals.rs");
+    println!("cargo:rerun-if-changed=src/primes.rs");
+
+    let tables = intervals::tables(&primes::PRIMES, &intervals::Rules::default());
+
+    let out_dir = env::var("OUT_DIR").expect("Cargo sets OUT_DIR for build scripts");
+    let mut file = BufWriter::new(File::create(Path::new(&out_dir).join("tables.rs"))?);
+
+    emit_tunings(&mut file, tables.len())?;
+    writeln!(
+        file,
+        "pub const TABLES: [[f64; 12*8]; {}] = [",
+        tables.len()
+    )?;
+
+    for table in &tables {
+        emit_table(&mut file, table)?;
+        writeln!(file, ",")?;
+    }
+
+    writeln!(file, "];")
+}
@@ -0,0 +1,129 @@
+use crate::tables::{
+    ELEVEN_LIMIT, FIVE_LIMIT, PYTHAGOREAN, SEVEN_LIMIT, SEVENTEEN_LIMIT, TABLES, THIRTEEN_LIMIT,
+};
+
+// Candidate tables, from the simplest prime limit up
+const LIMITS: [usize; 6] = [
+    PYTHAGOREAN as usize,
+    FIVE_LIMIT as usize,
+    SEVEN_LIMIT as usize,
+    ELEVEN_LIMIT as usize,
+    THIRTEEN_LIMIT as usize,
+    SEVENTEEN_LIMIT as usize,
+];
+
+// The table and fundamental pitch class giving the simplest ratios from the fundamental to
+// every note. Ties go to the lower limit and then to the preferred pitch class
+pub fn best_fit(notes: impl Iterator<Item = u8> + Clone, preferred: u8) -> Option<(usize, u8)> {
+    let mut best: Option<(f64, usize, u8)> = None;
+
+    for table in LIMITS {
+        for offset in 0..12 {
+            let pitch_class = (preferred + offset) % 12;
+            let score = notes
+                .clone()
+                .map(|note| {
+                    let ratio = TABLES[table][(note as usize + 12 - pitch_class as usize) % 12];
+
+                    (ratio > 0.0).then(|| height(ratio))
+                })
+                .sum::<Option<f64>>();
+
+            if let Some(score) = score
+                && best.is_none_or(|(lowest, ..)| score < lowest - 1e-9)
+            {
+                best = Some((score, table, pitch_class));
+            }
+        }
+    }
+
+    best.map(|(_, table, pitch_class)| (table, pitch_class))
+}
+
+// Tenney height, the log of numerator times denominator
+fn height(ratio: f64) -> f64 {
+    let (numerator, denominator) = rational(ratio);
+
+    (numerator as f64 * denominator as f64).log2()
+}
+
+// Table entries are ratios of small integers, so the continued fraction ends quickly
+fn rational(x: f64) -> (u64, u64) {
+    let (mut numerator, mut previous_numerator) = (1u64, 0u64);
+    let (mut denominator, mut previous_denominator) = (0u64, 1u64);
+    let mut rest = x;
+
+    loop {
+        let term = rest.floor() as u64;
+        let next = term
+            .checked_mul(numerator)
+            .and_then(|n| n.checked_add(previous_numerator))
+            .zip(
+                term.checked_mul(denominator)
+                    .and_then(|d| d.checked_add(previous_denominator)),
+            );
+
+        let Some((next_numerator, next_denominator)) = next else {
+            return (numerator, denominator);
+        };
+
+        (previous_numerator, numerator) = (numerator, next_numerator);
+        (previous_denominator, denominator) = (denominator, next_denominator);
+
+        let fraction = rest - rest.floor();
+
+        if (numerator as f64 / denominator as f64 - x).abs() < x * 1e-12 || fraction < 1e-12 {
+            return (numerator, denominator);
+        }
+
+        rest = 1.0 / fraction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovers_table_ratios() {
+        assert_eq!(rational(1.25), (5, 4));
+        assert_eq!(rational(3.0), (3, 1));
+        assert_eq!(rational(7.0 / 4.0), (7, 4));
+        assert_eq!(rational(2187.0 / 2048.0), (2187, 2048));
+    }
+
+    #[test]
+    fn fits_a_major_triad_to_five_limit_on_its_root() {
+        assert_eq!(
+            best_fit([60, 64, 67].into_iter(), 9),
+            Some((FIVE_LIMIT as usize, 0))
+        );
+        // Inversions keep the root
+        assert_eq!(
+            best_fit([64, 67, 72].into_iter(), 9),
+            Some((FIVE_LIMIT as usize, 0))
+        );
+    }
+
+    #[test]
+    fn fits_a_harmonic_seventh_to_seven_limit() {
+        assert_eq!(
+            best_fit([60, 64, 67, 70].into_iter(), 0),
+            Some((SEVEN_LIMIT as usize, 0))
+        );
+    }
+
+    #[test]
+    fn prefers_the_simplest_limit_and_the_given_pitch_class() {
+        assert_eq!(
+            best_fit([62].into_iter(), 2),
+            Some((PYTHAGOREAN as usize, 2))
+        );
+        assert_eq!(best_fit([].into_iter(), 5), Some((PYTHAGOREAN as usize, 5)));
+        // 3/2 above the lower note beats 4/3 below the upper one
+        assert_eq!(
+            best_fit([60, 67].into_iter(), 7),
+            Some((PYTHAGOREAN as usize, 0))
+        );
+    }
+}
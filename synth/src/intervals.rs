@@ -0,0 +1,278 @@
+// Builds the dynamic tuning tables: the simplest just interval in each of the 12 semitone
+// categories that a set of primes can reach. Also compiled into the build script, so this
+// only depends on std
+use std::collections::BTreeSet;
+
+// What counts as an interval worth picking
+#[derive(Clone, Copy, Debug)]
+pub struct Rules {
+    // Intervals with larger numerators are too complex to use, the apotome's by default
+    pub max_numerator: u64,
+    // Largest odd part allowed in numerator and denominator, when given
+    pub odd_limit: Option<u64>,
+    // How many intervals to stack up before picking
+    pub search_size: usize,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            max_numerator: 2187,
+            odd_limit: None,
+            search_size: 50,
+        }
+    }
+}
+
+// Exponents of 2 and of each prime in turn
+type Monzo = Vec<i32>;
+
+// One table per subset of the primes, the lowest bit of the index standing for the first prime
+pub fn tables(primes: &[u32], rules: &Rules) -> Vec<[f64; 12]> {
+    (0..1usize << primes.len())
+        .map(|index| {
+            let subset: Vec<u32> = primes
+                .iter()
+                .enumerate()
+                .filter(|&(bit, _)| index & 1 << bit != 0)
+                .map(|(_, &prime)| prime)
+                .collect();
+
+            table(&subset, rules)
+        })
+        .collect()
+}
+
+// Ratios above the fundamental for each semitone, zero where no interval fits
+pub fn table(primes: &[u32], rules: &Rules) -> [f64; 12] {
+    let mut simplest: [Option<(u64, u64)>; 12] = [None; 12];
+
+    for monzo in stack(primes, rules.search_size) {
+        let Some((numerator, denominator)) = fraction(primes, &monzo) else {
+            continue;
+        };
+
+        if numerator > rules.max_numerator
+            || rules
+                .odd_limit
+                .is_some_and(|limit| odd_part(numerator).max(odd_part(denominator)) > limit)
+        {
+            continue;
+        }
+
+        let category = category(log2(primes, &monzo) * 1200.0);
+        let slot = &mut simplest[category];
+
+        // The smallest numerator wins, and of those the narrowest interval
+        if slot.is_none_or(|(n, d)| numerator < n || numerator == n && denominator > d) {
+            *slot = Some((numerator, denominator));
+        }
+    }
+
+    simplest.map(|interval| interval.map_or(0.0, |(n, d)| n as f64 / d as f64))
+}
+
+// Repeatedly stacks the primes on everything found so far, along with the differences
+// between intervals, until there are enough to choose from
+fn stack(primes: &[u32], search_size: usize) -> BTreeSet<Monzo> {
+    let unison: Monzo = vec![0; primes.len() + 1];
+    let mut intervals = BTreeSet::from([unison]);
+
+    if primes.is_empty() {
+        return intervals;
+    }
+
+    while intervals.len() < search_size {
+        let mut found = BTreeSet::new();
+
+        for interval in &intervals {
+            for prime in 1..=primes.len() {
+                for step in [1, -1] {
+                    let mut stacked = interval.clone();
+                    stacked[prime] += step;
+                    found.insert(octave_reduce(primes, stacked));
+                }
+            }
+
+            for other in &intervals {
+                let (bigger, smaller) = if log2(primes, interval) >= log2(primes, other) {
+                    (interval, other)
+                } else {
+                    (other, interval)
+                };
+                let difference = bigger.iter().zip(smaller).map(|(a, b)| a - b).collect();
+
+                found.insert(octave_reduce(primes, difference));
+            }
+        }
+
+        intervals.extend(found);
+    }
+
+    intervals
+}
+
+fn log2(primes: &[u32], monzo: &Monzo) -> f64 {
+    monzo[0] as f64
+        + primes
+            .iter()
+            .zip(&monzo[1..])
+            .map(|(&prime, &exponent)| exponent as f64 * (prime as f64).log2())
+            .sum::<f64>()
+}
+
+// Into the octave above the fundamental
+fn octave_reduce(primes: &[u32], mut monzo: Monzo) -> Monzo {
+    monzo[0] = 0;
+    monzo[0] = -log2(primes, &monzo).floor() as i32;
+
+    monzo
+}
+
+// None when either side is too large to matter
+fn fraction(primes: &[u32], monzo: &Monzo) -> Option<(u64, u64)> {
+    let mut numerator: u64 = 1;
+    let mut denominator: u64 = 1;
+
+    for (&factor, &exponent) in [2].iter().chain(primes).zip(monzo) {
+        let power = (factor as u64).checked_pow(exponent.unsigned_abs())?;
+
+        if exponent > 0 {
+            numerator = numerator.checked_mul(power)?;
+        } else {
+            denominator = denominator.checked_mul(power)?;
+        }
+    }
+
+    Some((numerator, denominator))
+}
+
+fn odd_part(mut n: u64) -> u64 {
+    while n > 1 && n.is_multiple_of(2) {
+        n /= 2;
+    }
+
+    n
+}
+
+// Semitones above the fundamental, each reaching 50 cents either side
+fn category(cents: f64) -> usize {
+    ((cents + 50.0) / 100.0).floor() as usize % 12
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratios(intervals: [(u64, u64); 12]) -> [f64; 12] {
+        intervals.map(|(n, d)| n as f64 / d as f64)
+    }
+
+    #[test]
+    fn picks_the_simplest_interval_in_each_semitone() {
+        let rules = Rules::default();
+
+        assert_eq!(
+            table(&[], &rules),
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            table(&[3], &rules),
+            ratios([
+                (1, 1),
+                (256, 243),
+                (9, 8),
+                (32, 27),
+                (81, 64),
+                (4, 3),
+                (729, 512),
+                (3, 2),
+                (128, 81),
+                (27, 16),
+                (16, 9),
+                (243, 128),
+            ])
+        );
+        assert_eq!(
+            table(&[3, 5], &rules),
+            ratios([
+                (1, 1),
+                (16, 15),
+                (9, 8),
+                (6, 5),
+                (5, 4),
+                (4, 3),
+                (25, 18),
+                (3, 2),
+                (8, 5),
+                (5, 3),
+                (9, 5),
+                (15, 8),
+            ])
+        );
+        assert_eq!(
+            table(&[3, 5, 7], &rules),
+            ratios([
+                (1, 1),
+                (15, 14),
+                (8, 7),
+                (6, 5),
+                (5, 4),
+                (4, 3),
+                (7, 5),
+                (3, 2),
+                (8, 5),
+                (5, 3),
+                (7, 4),
+                (15, 8),
+            ])
+        );
+    }
+
+    // The Python script these tables came from kept whichever tie its set iterated over
+    // first, so tables 66, 82, 552, 594 and 680 changed when the choice became stable
+    #[test]
+    fn ties_go_to_the_narrowest_interval() {
+        let rules = Rules::default();
+
+        assert_eq!(table(&[5, 19], &rules)[6], 361.0 / 256.0);
+        assert_eq!(table(&[5, 13, 19], &rules)[6], 361.0 / 260.0);
+        assert_eq!(table(&[5, 23], &rules)[3], 625.0 / 529.0);
+        assert_eq!(table(&[11, 17, 31], &rules)[3], 289.0 / 248.0);
+        assert_eq!(table(&[11, 17, 31], &rules)[7], 187.0 / 128.0);
+    }
+
+    #[test]
+    fn intervals_past_the_limits_are_left_out() {
+        let rules = Rules {
+            odd_limit: Some(5),
+            ..Rules::default()
+        };
+
+        assert_eq!(table(&[3, 5, 7], &rules), table(&[3, 5], &rules));
+        assert_eq!(
+            table(&[3], &rules),
+            [
+                1.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                4.0 / 3.0,
+                0.0,
+                1.5,
+                0.0,
+                0.0,
+                0.0,
+                0.0
+            ]
+        );
+    }
+
+    #[test]
+    fn odd_parts_drop_every_factor_of_two() {
+        assert_eq!(odd_part(1), 1);
+        assert_eq!(odd_part(96), 3);
+        assert_eq!(odd_part(128), 1);
+    }
+}
@@ -1,29 +1,114 @@
 use std::array;
 use std::collections::BTreeSet;
+use std::str::FromStr;
 
 use crate::oscillator::Waveform;
+use crate::pressure::PressureTarget;
 use crate::tables::TABLES;
+use crate::velocity::VelocityCurve;
 use crate::voice::Voice;
 use serde::{Deserialize, Serialize};
 use tables::PYTHAGOREAN;
 
-mod build;
+mod adaptive;
 mod envelope;
+pub mod intervals;
 mod modulator;
 pub mod oscillator;
+pub mod pressure;
+pub mod primes;
 mod tables;
+pub mod velocity;
 mod voice;
 
 pub const SAMPLE_RATE: u32 = 44100;
 
 pub type SF = i16;
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Mode {
     Fixed,
     Dynamic,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum BendMode {
+    #[default]
+    Semitones,
+    ScaleSteps,
+}
+
+// Keeps the dynamic fundamental from wandering away from the reference
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Anchor {
+    #[default]
+    Free,
+    // Back to the reference once nothing sounds
+    Silence,
+    // At most this many cents away from the reference
+    Bounded(f64),
+    // Every new fundamental sits on the nearest 12-TET pitch
+    Recenter,
+}
+
+impl FromStr for Anchor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "free" => Ok(Anchor::Free),
+            "silence" => Ok(Anchor::Silence),
+            "recenter" => Ok(Anchor::Recenter),
+            cents => cents
+                .parse::<f64>()
+                .ok()
+                .filter(|cents| *cents >= 0.0)
+                .map(Anchor::Bounded)
+                .ok_or_else(|| {
+                    format!("Expected free, silence, recenter or a number of cents, got {s}")
+                }),
+        }
+    }
+}
+
+fn default_bend_range() -> u8 {
+    2
+}
+
+fn default_slide_target() -> PressureTarget {
+    PressureTarget::Modulator1Amount
+}
+
+// The voice owned by an MPE member channel
+#[derive(Clone)]
+struct Member {
+    voice: Voice,
+    note: u8,
+    held: bool,
+    sustained: bool,
+    bend: f64,
+}
+
+impl Member {
+    fn new() -> Self {
+        let mut voice = Voice::new(0.0, 0);
+        voice.enabled = false;
+
+        Self {
+            voice,
+            note: 0,
+            held: false,
+            sustained: false,
+            bend: 0.0,
+        }
+    }
+
+    fn is_sounding(&self) -> bool {
+        self.held || self.sustained
+    }
+}
+
 #[derive(Clone, Copy, Serialize, Deserialize)]
 pub struct SynthSetting {
     oscillator1_waveform: Waveform,
@@ -61,6 +146,21 @@ pub struct SynthSetting {
     modulator2_amount_spectrum: u8,
     vibrato_depth: u8,
     oscillator_balance: u8,
+    #[serde(default)]
+    velocity_curve: VelocityCurve,
+    #[serde(default)]
+    velocity_brightness: u8,
+    #[serde(default = "default_bend_range")]
+    bend_range: u8,
+    #[serde(default)]
+    bend_mode: BendMode,
+    #[serde(default)]
+    pressure_target: PressureTarget,
+    #[serde(default = "default_slide_target")]
+    slide_target: PressureTarget,
+    // Milliseconds sounding voices take to reach a new tuning bank
+    #[serde(default)]
+    retune_glide: u16,
 }
 
 impl Default for SynthSetting {
@@ -101,43 +201,100 @@ impl Default for SynthSetting {
             modulator2_amount_spectrum: 1,
             vibrato_depth: 5,
             oscillator_balance: 127,
+            velocity_curve: VelocityCurve::default(),
+            velocity_brightness: 0,
+            bend_range: default_bend_range(),
+            bend_mode: BendMode::Semitones,
+            pressure_target: PressureTarget::None,
+            slide_target: default_slide_target(),
+            retune_glide: 0,
         }
     }
 }
 
+// A user interval table for dynamic mode, ratios above the fundamental for each semitone
+#[derive(Clone, Debug)]
+pub struct DynamicTable {
+    pub name: String,
+    pub intervals: [f64; 12],
+}
+
+// The tuning slot MTS messages retune when no fixed tuning is playing
+const LIVE_TUNING: &str = "live";
+
+#[derive(Clone, Debug)]
+pub struct TuningPreset {
+    pub name: String,
+    pub frequencies: [f64; 128],
+}
+
+// Where dynamic tuning stands: the table and the fundamental notes are tuned from. Synths
+// playing together share one, so they stay in tune with each other as it moves
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Frame {
+    table: usize,
+    dynamic_table: Option<usize>,
+    note: u8,
+    freq: f64,
+}
+
+// The rest of what the tuning actions change
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TuningState {
+    mode: Mode,
+    tuning_index: usize,
+    adaptive: bool,
+    drift_compensation: bool,
+}
+
 #[derive(Clone)]
 pub struct Synth {
     // TODO visibility
     pub mode: Mode,
     pub timbre_presets: [SynthSetting; 8],
-    pub tuning_presets: Option<[[f64; 128]; 24]>,
-    // TODO allow for manual and pedals
+    pub tuning_presets: Vec<TuningPreset>,
     voices: [Voice; 109],
     active_voices: BTreeSet<u8>,
     table: usize,
+    // User tables spread over the octaves like TABLES, the selected one stands in for the
+    // built-in table
+    dynamic_tables: Vec<(String, [f64; 12 * 8])>,
+    dynamic_table: Option<usize>,
     last_note: u8,
     last_freq: f64,
+    // Concert pitch the fundamental is measured from
+    reference_note: u8,
+    reference_freq: f64,
+    // Refit the fundamental and prime limit to the held notes on every note on
+    adaptive: bool,
+    drift_compensation: bool,
+    anchor: Anchor,
+    // Notes held by synths sharing this one's frame, adapting fits them too and silence waits
+    // for them
+    accompaniment: BTreeSet<u8>,
     volume: f64,
     sustain: bool,
     sustained_voices: BTreeSet<u8>,
     timbre_index: usize,
     tuning_index: usize,
+    pitch_bend: f64,
+    channel_pressure: u8,
+    members: [Member; 16],
+    member_bend_range: u8,
 }
 
 // TODO Refactor with forall_voices or something similar
 impl Synth {
-    const VOLUME: u8 = 127;
-
     // TODO Magic numbers
     // TODO active_tuning to ignore tuning note offs when fixing
     // TODO AND ... Send NoteOffs for all active Control notes
     pub fn new(
         timbre_presets: [SynthSetting; 8],
-        tuning_presets: Option<[[f64; 128]; 24]>,
+        tuning_presets: Vec<TuningPreset>,
         base_freq: f64,
         base_note: u8,
     ) -> Self {
-        let mode = if tuning_presets.is_some() {
+        let mode = if !tuning_presets.is_empty() {
             Mode::Fixed
         } else {
             Mode::Dynamic
@@ -147,10 +304,18 @@ impl Synth {
             voices: array::from_fn(|_| Voice::new(0.0, 0)),
             active_voices: BTreeSet::new(),
             table: PYTHAGOREAN as usize,
+            dynamic_tables: Vec::new(),
+            dynamic_table: None,
             last_note: base_note,
             // last_note: 69,
             last_freq: base_freq,
             // last_freq: 440.0,
+            reference_note: base_note,
+            reference_freq: base_freq,
+            adaptive: false,
+            drift_compensation: false,
+            anchor: Anchor::Free,
+            accompaniment: BTreeSet::new(),
             volume: 1.0,
             sustain: false,
             sustained_voices: BTreeSet::new(),
@@ -159,77 +324,441 @@ impl Synth {
             timbre_index: 0,
             tuning_presets,
             tuning_index: 0,
+            pitch_bend: 0.0,
+            channel_pressure: 0,
+            members: array::from_fn(|_| Member::new()),
+            // MPE default for member channels
+            member_bend_range: 48,
         }
     }
 
-    // TODO Make an enum and perform the translation elsewhere?
-    // TODO Magic numbers
+    // Toggles the prime a key above middle C stands for, the octave above resets to
+    // Pythagorean
     pub fn change_tuning(&mut self, note: u8) {
-        match note - 60 {
-            1 => self.table ^= 0b00_0010_0000,
-            3 => self.table ^= 0b00_0100_0000,
-            4 => self.table ^= 0b00_0000_0010,
-            5 => self.table ^= 0b00_0000_1000,
-            6 => self.table ^= 0b00_1000_0000,
-            7 => self.table ^= 0b00_0000_0001,
-            8 => self.table ^= 0b00_0001_0000,
-            9 => self.table ^= 0b00_0000_0100,
-            10 => self.table ^= 0b01_0000_0000,
-            11 => self.table ^= 0b10_0000_0000,
-            12 => self.table = 0b00_0000_0001,
-            _ => {}
+        match note.checked_sub(60) {
+            Some(12) => {
+                self.set_primes(&[3]);
+            }
+            Some(semitones) => {
+                if let Some(prime) = primes::key(semitones) {
+                    self.toggle_prime(prime);
+                }
+            }
+            None => {}
+        }
+    }
+
+    // Selects the table built from exactly these primes, false when one has no table
+    pub fn set_primes(&mut self, primes: &[u32]) -> bool {
+        let Some(table) = primes::table(primes) else {
+            return false;
+        };
+
+        self.change_table(table);
+
+        true
+    }
+
+    pub fn toggle_prime(&mut self, prime: u32) -> bool {
+        let Some(bit) = primes::bit(prime) else {
+            return false;
+        };
+
+        self.change_table(self.table ^ bit);
+
+        true
+    }
+
+    // Every prime up to and including the limit
+    pub fn set_prime_limit(&mut self, limit: u32) {
+        self.change_table(primes::limit(limit));
+    }
+
+    pub fn add_dynamic_table(&mut self, table: DynamicTable) -> usize {
+        let intervals = array::from_fn(|i| table.intervals[i % 12] * 2.0_f64.powi(i as i32 / 12));
+
+        self.dynamic_tables.push((table.name, intervals));
+
+        self.dynamic_tables.len() - 1
+    }
+
+    pub fn change_dynamic_table(&mut self, index: usize) {
+        let Some((name, _)) = self.dynamic_tables.get(index) else {
+            return;
+        };
+
+        println!("Dynamic table {index}: {name}");
+        self.dynamic_table = Some(index);
+
+        self.retune();
+    }
+
+    // The selected user table, or else the built-in one for the active primes
+    fn intervals(&self) -> &[f64] {
+        match self
+            .dynamic_table
+            .and_then(|index| self.dynamic_tables.get(index))
+        {
+            Some((_, intervals)) => intervals,
+            None => &TABLES[self.table],
         }
+    }
+
+    pub fn primes(&self) -> Vec<u32> {
+        primes::primes(self.table)
+    }
+
+    fn change_table(&mut self, table: usize) {
+        self.table = table;
+        self.dynamic_table = None;
 
+        println!("Primes: {:?}", self.primes());
         self.retune();
     }
 
     // TODO Magic numbers
     pub fn change_fundamental(&mut self, note: u8) {
-        let normalized_base = (note + 12) as i8;
-        let interval = normalized_base - 69;
+        // The fundamental sounds an octave above its key and has to stay a MIDI note
+        let Some(normalized_base) = note.checked_add(12).filter(|&base| base < 128) else {
+            return;
+        };
+        let interval = normalized_base as i8 - self.reference_note as i8;
 
-        if let Some(freq) = Self::transform_freq(440.0, interval, &TABLES[self.table]) {
-            self.last_note = normalized_base as u8;
+        // Measured from the reference so that fundamentals only drift through adaptation
+        if let Some(freq) = Self::transform_freq(self.reference_freq, interval, self.intervals()) {
+            self.last_note = normalized_base;
             self.last_freq = freq;
+            self.apply_anchor();
             self.retune();
         }
         // self.log();
     }
 
-    fn retune(&mut self) {
-        // TODO why is this check necessary?
-        if !self.active_voices.is_empty() {
-            let (base_freq, current_note) = (self.last_freq, self.last_note);
+    pub fn set_adaptive_tuning(&mut self, enabled: bool) {
+        self.adaptive = enabled;
+    }
+
+    pub fn toggle_adaptive_tuning(&mut self) {
+        self.adaptive = !self.adaptive;
+
+        println!(
+            "Adaptive tuning {}",
+            if self.adaptive { "on" } else { "off" }
+        );
+    }
+
+    pub fn set_drift_compensation(&mut self, enabled: bool) {
+        self.drift_compensation = enabled;
+    }
+
+    pub fn toggle_drift_compensation(&mut self) {
+        self.drift_compensation = !self.drift_compensation;
+
+        println!(
+            "Drift compensation {}",
+            if self.drift_compensation { "on" } else { "off" }
+        );
+    }
+
+    // Picks the fundamental and prime limit that fit the notes sounding once `note` starts.
+    // The lowest held note keeps its pitch across the change unless drift is compensated,
+    // which tunes the fundamental from the reference instead
+    fn adapt(&mut self, note: u8) {
+        if self.mode != Mode::Dynamic || !self.adaptive {
+            return;
+        }
+
+        let mut notes = self.held_notes();
+        notes.extend(&self.accompaniment);
+
+        let anchor = notes
+            .first()
+            .and_then(|&held| Some((held, self.table_freq(held as i32)?)));
+
+        notes.insert(note);
+
+        let Some((table, pitch_class)) =
+            adaptive::best_fit(notes.iter().copied(), self.last_note % 12)
+        else {
+            return;
+        };
+
+        // The fundamental sits at or below the lowest note
+        let lowest = *notes.first().unwrap() as i32;
+        let fundamental = lowest - (lowest + 12 - pitch_class as i32) % 12;
+        let fundamental = if fundamental < 0 {
+            fundamental + 12
+        } else {
+            fundamental
+        };
+
+        let from_reference = self.reference_tuned(fundamental, &TABLES[table]);
+
+        let freq = if self.drift_compensation {
+            from_reference
+        } else if let Some((held, freq)) = anchor {
+            Self::transform_freq(1.0, (held as i32 - fundamental) as i8, &TABLES[table])
+                .map_or(from_reference, |ratio| freq / ratio)
+        } else {
+            Self::transform_freq(
+                self.last_freq,
+                (fundamental - self.last_note as i32) as i8,
+                self.intervals(),
+            )
+            .unwrap_or(from_reference)
+        };
+
+        self.table = table;
+        self.dynamic_table = None;
+        self.last_note = fundamental as u8;
+        self.last_freq = freq;
+        self.apply_anchor();
+
+        self.retune();
+    }
+
+    pub fn set_anchor(&mut self, anchor: Anchor) {
+        self.anchor = anchor;
+    }
 
-            for &note in &self.active_voices {
-                let oscillator = &mut self.voices[note as usize];
+    // The note as the table tunes it straight from the reference, 12-TET where it has no entry
+    fn reference_tuned(&self, note: i32, intervals: &[f64]) -> f64 {
+        let interval = note - self.reference_note as i32;
 
-                let interval = note as i8 - current_note as i8;
+        Self::transform_freq(self.reference_freq, interval as i8, intervals)
+            .unwrap_or_else(|| self.equal_tempered(note))
+    }
+
+    fn equal_tempered(&self, note: i32) -> f64 {
+        self.reference_freq * 2.0_f64.powf((note - self.reference_note as i32) as f64 / 12.0)
+    }
+
+    // Cents the dynamic fundamental has drifted from where the reference puts it
+    pub fn drift(&self) -> f64 {
+        let reference = self.reference_tuned(self.last_note as i32, self.intervals());
 
-                if let Some(freq) = Self::transform_freq(base_freq, interval, &TABLES[self.table]) {
-                    oscillator.set_freq(freq);
+        1200.0 * (self.last_freq / reference).log2()
+    }
+
+    pub fn report_drift(&self) {
+        println!(
+            "Fundamental {} at {:.3} Hz, {:+.2} cents from the reference",
+            self.last_note,
+            self.last_freq,
+            self.drift()
+        );
+    }
+
+    fn apply_anchor(&mut self) {
+        let note = self.last_note as i32;
+
+        match self.anchor {
+            Anchor::Free | Anchor::Silence => {}
+            Anchor::Bounded(cents) => {
+                let drift = self.drift();
+
+                if drift.abs() > cents {
+                    self.last_freq /= 2.0_f64.powf((drift - cents.copysign(drift)) / 1200.0);
                 }
             }
+            Anchor::Recenter => self.last_freq = self.equal_tempered(note),
+        }
+    }
+
+    fn is_silent(&self) -> bool {
+        self.active_voices.is_empty()
+            && self.sustained_voices.is_empty()
+            && !self.members.iter().any(Member::is_sounding)
+            && self.accompaniment.is_empty()
+    }
+
+    // Sounding notes, sustained ones included
+    pub fn held_notes(&self) -> BTreeSet<u8> {
+        let mut notes: BTreeSet<u8> = self
+            .active_voices
+            .union(&self.sustained_voices)
+            .copied()
+            .collect();
+        notes.extend(
+            self.members
+                .iter()
+                .filter(|member| member.is_sounding())
+                .map(|member| member.note),
+        );
+
+        notes
+    }
+
+    pub fn set_accompaniment(&mut self, notes: BTreeSet<u8>) {
+        self.accompaniment = notes;
+    }
+
+    pub fn frame(&self) -> Frame {
+        Frame {
+            table: self.table,
+            dynamic_table: self.dynamic_table,
+            note: self.last_note,
+            freq: self.last_freq,
+        }
+    }
+
+    // Sounding voices follow into the frame
+    pub fn set_frame(&mut self, frame: Frame) {
+        if frame == self.frame() {
+            return;
+        }
+
+        self.table = frame.table;
+        self.dynamic_table = frame.dynamic_table;
+        self.last_note = frame.note;
+        self.last_freq = frame.freq;
+
+        self.retune();
+    }
+
+    pub fn tuning_state(&self) -> TuningState {
+        TuningState {
+            mode: self.mode,
+            tuning_index: self.tuning_index,
+            adaptive: self.adaptive,
+            drift_compensation: self.drift_compensation,
+        }
+    }
+
+    // Takes over what another synth's tuning actions did, without reporting them again.
+    // Changing the slot glides like changing the bank
+    pub fn set_tuning_state(&mut self, state: TuningState) {
+        if state == self.tuning_state() {
+            return;
+        }
+
+        let glide = if state.tuning_index != self.tuning_index {
+            self.timbre_presets[self.timbre_index].retune_glide
+        } else {
+            0
+        };
+
+        self.mode = state.mode;
+        self.tuning_index = state.tuning_index;
+        self.adaptive = state.adaptive;
+        self.drift_compensation = state.drift_compensation;
+
+        self.glide_retune((glide as u64 * SAMPLE_RATE as u64 / 1000) as u32);
+    }
+
+    // Snaps back once the last note is released, nothing is left to jump
+    fn anchor_on_silence(&mut self) {
+        if self.anchor == Anchor::Silence && self.is_silent() {
+            self.last_freq = self.reference_tuned(self.last_note as i32, self.intervals());
+        }
+    }
+
+    // Sustained voices are retuned as well, they are still sounding
+    fn retune(&mut self) {
+        self.glide_retune(0);
+    }
+
+    fn glide_retune(&mut self, samples: u32) {
+        let notes: Vec<u8> = self
+            .active_voices
+            .union(&self.sustained_voices)
+            .copied()
+            .collect();
+
+        for note in notes {
+            if let Some(freq) = self.table_freq(note as i32) {
+                let freq = self.bend(note, freq, self.bend_amount());
+
+                self.voices[note as usize].glide_freq(freq, samples);
+            }
+        }
+
+        for channel in 0..self.members.len() {
+            self.retune_member(channel, samples);
         }
         // self.log();
     }
 
-    fn retune_fixed(&mut self) {
-        for &note in &self.active_voices {
-            let oscillator = &mut self.voices[note as usize];
+    fn retune_member(&mut self, channel: usize, samples: u32) {
+        let member = &self.members[channel];
 
-            let freq = self.tuning_presets.unwrap()[self.tuning_index][note as usize];
+        if !member.is_sounding() {
+            return;
+        }
+
+        let note = member.note;
+        let amount = self.bend_amount() + member.bend * self.member_bend_range as f64;
 
-            oscillator.set_freq(freq);
+        if let Some(freq) = self.table_freq(note as i32) {
+            let freq = self.bend(note, freq, amount);
+
+            self.members[channel].voice.glide_freq(freq, samples);
+        }
+    }
+
+    fn all_voices(&mut self) -> impl Iterator<Item = &mut Voice> {
+        self.voices
+            .iter_mut()
+            .chain(self.members.iter_mut().map(|member| &mut member.voice))
+    }
+
+    // The unbent frequency of every note, zero where nothing is tuned
+    pub fn frequencies(&self) -> [f64; 128] {
+        array::from_fn(|note| self.table_freq(note as i32).unwrap_or(0.0))
+    }
+
+    fn table_freq(&self, note: i32) -> Option<f64> {
+        if !(0..128).contains(&note) {
+            return None;
+        }
+
+        match self.mode {
+            Mode::Fixed => self
+                .tuning_presets
+                .get(self.tuning_index)
+                .map(|tuning| tuning.frequencies[note as usize])
+                .filter(|&freq| freq > 0.0),
+            Mode::Dynamic => Self::transform_freq(
+                self.last_freq,
+                (note - self.last_note as i32) as i8,
+                self.intervals(),
+            ),
+        }
+    }
+
+    fn bend_amount(&self) -> f64 {
+        self.pitch_bend * self.timbre_presets[self.timbre_index].bend_range as f64
+    }
+
+    // `amount` is in semitones or scale steps depending on the bend mode
+    fn bend(&self, note: u8, freq: f64, amount: f64) -> f64 {
+        if amount == 0.0 {
+            return freq;
+        }
+
+        let settings = self.timbre_presets[self.timbre_index];
+
+        // Glide between neighbouring notes of the table, falling back to semitones at gaps
+        if settings.bend_mode == BendMode::ScaleSteps {
+            let steps = amount.floor();
+            let fraction = amount - steps;
+            let from = note as i32 + steps as i32;
+
+            if let (Some(low), Some(high)) = (self.table_freq(from), self.table_freq(from + 1)) {
+                return low * (high / low).powf(fraction);
+            }
         }
+
+        freq * 2.0_f64.powf(amount / 12.0)
     }
+
     fn transform_freq(mut freq: f64, mut midi_interval: i8, interval_table: &[f64]) -> Option<f64> {
         while midi_interval < 0 {
             midi_interval += 12;
             freq /= 2.0;
         }
 
-        let interval = interval_table[midi_interval as usize];
+        let interval = interval_table.get(midi_interval as usize).copied()?;
 
         if interval == 0.0 {
             None
@@ -238,16 +767,31 @@ impl Synth {
         }
     }
 
-    fn play_note_with_freq_and_vol(&mut self, note: u8, freq: f64, _vol: u8) {
-        // let vol = vol as u16;
+    fn start_voice(voice: &mut Voice, settings: SynthSetting, freq: f64, vol: u8, pressure: u8) {
+        let velocity = settings.velocity_curve.apply(vol);
+        let brightness = settings.velocity_brightness as f64 / 127.0;
 
-        let voice = &mut self.voices[note as usize];
         voice.enabled = true;
         voice.set_freq(freq);
+        voice.set_velocity(velocity, 1.0 - brightness + brightness * velocity);
+        voice.set_pressure(pressure);
         // voice.env.set_volume(vol);
         voice.env.set_volume(255);
         voice.modulator1_env.set_volume(255);
         voice.modulator2_env.set_volume(255);
+    }
+
+    // Notes above the highest voice are ignored
+    fn play_note_with_freq_and_vol(&mut self, note: u8, freq: f64, vol: u8) {
+        let settings = self.timbre_presets[self.timbre_index];
+        let freq = self.bend(note, freq, self.bend_amount());
+        let pressure = self.channel_pressure;
+
+        let Some(voice) = self.voices.get_mut(note as usize) else {
+            return;
+        };
+
+        Self::start_voice(voice, settings, freq, vol, pressure);
 
         self.active_voices.insert(note);
 
@@ -257,7 +801,7 @@ impl Synth {
     }
 
     pub fn set_vibrato(&mut self, freq: f64) {
-        for voice in &mut self.voices {
+        for voice in self.all_voices() {
             voice.set_vibrato(freq);
         }
     }
@@ -266,21 +810,22 @@ impl Synth {
         self.volume = vol as f64 / 127.0;
     }
 
-    pub fn play(&mut self, note: u8) {
+    pub fn play(&mut self, note: u8, velocity: u8) {
+        self.adapt(note);
+
         let note = note as i8;
         let last_note = self.last_note as i8;
         let interval = note - last_note;
 
-        if let Some(freq) = Self::transform_freq(self.last_freq, interval, &TABLES[self.table]) {
-            self.play_note_with_freq_and_vol(note as u8, freq, Self::VOLUME);
+        if let Some(freq) = Self::transform_freq(self.last_freq, interval, self.intervals()) {
+            self.play_note_with_freq_and_vol(note as u8, freq, velocity);
         }
         // self.log();
     }
-    pub fn play_fixed(&mut self, note: u8) {
-        // TODO unwrap_unchecked?
-        let freq = self.tuning_presets.unwrap()[self.tuning_index][note as usize];
-
-        self.play_note_with_freq_and_vol(note, freq, Self::VOLUME);
+    pub fn play_fixed(&mut self, note: u8, velocity: u8) {
+        if let Some(freq) = self.table_freq(note as i32) {
+            self.play_note_with_freq_and_vol(note, freq, velocity);
+        }
     }
 
     // fn log(&self) {
@@ -298,30 +843,108 @@ impl Synth {
     pub fn silence(&mut self, note: u8) {
         self.active_voices.remove(&note);
 
-        if !self.sustained_voices.contains(&note) {
-            self.voices[note as usize].env.set_volume(0);
+        if !self.sustained_voices.contains(&note)
+            && let Some(voice) = self.voices.get_mut(note as usize)
+        {
+            voice.env.set_volume(0);
+        }
+
+        self.anchor_on_silence();
+    }
+
+    // MPE member channels play through their own voice so the same note can sound on
+    // several channels, each with its own bend, pressure and slide
+    pub fn play_member(&mut self, channel: u8, note: u8, velocity: u8) {
+        if channel as usize >= self.members.len() {
+            return;
+        }
+
+        self.adapt(note);
+
+        let member = &mut self.members[channel as usize];
+
+        member.note = note;
+        member.held = true;
+        member.sustained = self.sustain;
+
+        let bend = member.bend;
+        let amount = self.bend_amount() + bend * self.member_bend_range as f64;
+
+        if let Some(freq) = self.table_freq(note as i32) {
+            let freq = self.bend(note, freq, amount);
+            let settings = self.timbre_presets[self.timbre_index];
+
+            Self::start_voice(
+                &mut self.members[channel as usize].voice,
+                settings,
+                freq,
+                velocity,
+                self.channel_pressure,
+            );
+        }
+    }
+
+    pub fn silence_member(&mut self, channel: u8, note: u8) {
+        let sustain = self.sustain;
+
+        if let Some(member) = self.members.get_mut(channel as usize)
+            && member.held
+            && member.note == note
+        {
+            member.held = false;
+
+            if !sustain {
+                member.voice.env.set_volume(0);
+            }
+        }
+
+        self.anchor_on_silence();
+    }
+
+    // -8192..=8191 like the MIDI pitch wheel
+    pub fn set_member_bend(&mut self, channel: u8, value: i16) {
+        if let Some(member) = self.members.get_mut(channel as usize) {
+            member.bend = value as f64 / 8192.0;
+
+            self.retune_member(channel as usize, 0);
+        }
+    }
+
+    pub fn set_member_bend_range(&mut self, value: u8) {
+        self.member_bend_range = value;
+
+        self.retune();
+    }
+
+    pub fn set_member_pressure(&mut self, channel: u8, value: u8) {
+        if let Some(member) = self.members.get_mut(channel as usize) {
+            member.voice.set_pressure(value);
+        }
+    }
+
+    pub fn set_member_slide(&mut self, channel: u8, value: u8) {
+        if let Some(member) = self.members.get_mut(channel as usize) {
+            member.voice.set_slide(value);
         }
     }
 
     pub fn set_modulator1_ratio(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_ratio = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1.set_ratio(value, voice.oscillator1.freq()));
     }
     pub fn set_modulator1_amount(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_amount = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1.set_amount(value));
     }
 
     pub fn set_modulator2_ratio(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_ratio = value;
 
-        self.voices.iter_mut().for_each(|voice| {
+        self.all_voices().for_each(|voice| {
             voice
                 .modulator2
                 .set_ratio(value, voice.modulator1.oscillator.freq())
@@ -330,106 +953,91 @@ impl Synth {
     pub fn set_modulator2_amount(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_amount = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2.set_amount(value));
     }
     pub fn set_oscillator1_waveform(&mut self, waveform: Waveform) {
         self.timbre_presets[self.timbre_index].oscillator1_waveform = waveform;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.oscillator1.set_waveform(waveform));
     }
 
     pub fn set_oscillator2_waveform(&mut self, waveform: Waveform) {
         self.timbre_presets[self.timbre_index].oscillator2_waveform = waveform;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.oscillator2.set_waveform(waveform));
     }
 
     pub fn set_modulator1_waveform(&mut self, waveform: Waveform) {
         self.timbre_presets[self.timbre_index].modulator1_waveform = waveform;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1.oscillator.set_waveform(waveform));
     }
 
     pub fn set_modulator2_waveform(&mut self, waveform: Waveform) {
         self.timbre_presets[self.timbre_index].modulator2_waveform = waveform;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2.oscillator.set_waveform(waveform));
     }
     pub fn set_oscillator1_duty(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].oscillator1_duty = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.oscillator1.set_duty(value));
     }
     pub fn set_oscillator2_duty(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].oscillator2_duty = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.oscillator2.set_duty(value));
     }
 
     pub fn set_modulator1_duty(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_duty = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1.oscillator.set_duty(value));
     }
     pub fn set_modulator2_duty(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_duty = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2.oscillator.set_duty(value));
     }
 
     pub fn set_gain(&mut self, value: u16) {
-        self.voices
-            .iter_mut()
-            .for_each(|voice| voice.set_gain(value));
+        self.all_voices().for_each(|voice| voice.set_gain(value));
     }
 
     pub fn set_attack(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].oscillator_attack = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.env.set_attack(value));
     }
 
     pub fn set_decay(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].oscillator_decay = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.env.set_decay(value));
     }
 
     pub fn set_sustain(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].oscillator_sustain = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.env.set_sustain(value));
     }
 
     pub fn set_release(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].oscillator_release = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.env.set_release(value));
     }
 
@@ -439,108 +1047,112 @@ impl Synth {
         for &note in self.active_voices.iter() {
             self.sustained_voices.insert(note);
         }
+
+        for member in self.members.iter_mut().filter(|member| member.held) {
+            member.sustained = true;
+        }
     }
 
     pub fn disable_sustain(&mut self) {
         self.sustain = false;
 
         for note in self.sustained_voices.iter() {
-            if !self.active_voices.contains(&note) {
+            if !self.active_voices.contains(note) {
                 self.voices[*note as usize].env.set_volume(0);
             }
         }
 
         self.sustained_voices.clear();
+
+        for member in self.members.iter_mut().filter(|member| member.sustained) {
+            if !member.held {
+                member.voice.env.set_volume(0);
+            }
+
+            member.sustained = false;
+        }
+
+        self.anchor_on_silence();
     }
     pub fn set_modulator1_attack(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_attack = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1_env.set_attack(value));
     }
 
     pub fn set_modulator1_decay(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_decay = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1_env.set_decay(value));
     }
 
     pub fn set_modulator1_sustain(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_sustain = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1_env.set_sustain(value));
     }
 
     pub fn set_modulator1_release(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_release = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1_env.set_release(value));
     }
     pub fn set_modulator2_attack(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_attack = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2_env.set_attack(value));
     }
 
     pub fn set_modulator2_decay(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_decay = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2_env.set_decay(value));
     }
 
     pub fn set_modulator2_sustain(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_sustain = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2_env.set_sustain(value));
     }
 
     pub fn set_modulator2_release(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_release = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2_env.set_release(value));
     }
 
     pub fn toggle_modulator1_env_repeat(&mut self) {
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1_env.toggle_repeat());
     }
     pub fn toggle_modulator2_env_repeat(&mut self) {
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2_env.toggle_repeat());
     }
     pub fn set_modulator1_env_repeat(&mut self, value: bool) {
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1_env.set_repeat(value));
     }
     pub fn set_modulator2_env_repeat(&mut self, value: bool) {
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2_env.set_repeat(value));
     }
 
     // TODO reset envelopes?
     pub fn change_timbre_bank(&mut self, index: usize) {
-        self.timbre_index = index;
+        let Some(&settings) = self.timbre_presets.get(index) else {
+            return;
+        };
 
-        let settings = self.timbre_presets[self.timbre_index];
+        self.timbre_index = index;
 
         self.set_oscillator1_waveform(settings.oscillator1_waveform);
         self.set_oscillator1_duty(settings.oscillator1_duty);
@@ -569,77 +1181,241 @@ impl Synth {
         self.set_modulator2_release(settings.modulator2_release);
         self.set_modulator2_env_repeat(settings.modulator1_env_repeat);
         self.set_oscillator_balance(settings.oscillator_balance);
+        self.set_pressure_target(settings.pressure_target);
+        self.set_slide_target(settings.slide_target);
+    }
+
+    pub fn timbre_index(&self) -> usize {
+        self.timbre_index
+    }
+
+    pub fn tuning_index(&self) -> usize {
+        self.tuning_index
+    }
+
+    pub fn set_tuning(&mut self, index: usize, frequencies: [f64; 128]) {
+        if let Some(tuning) = self.tuning_presets.get_mut(index) {
+            tuning.frequencies = frequencies;
+        }
+
+        if index == self.tuning_index {
+            self.retune();
+        }
+    }
+
+    // Replaces the whole bank, staying on the same slot when there still is one
+    pub fn set_tuning_presets(&mut self, tuning_presets: Vec<TuningPreset>) {
+        self.tuning_presets = tuning_presets;
+        self.tuning_index = self
+            .tuning_index
+            .min(self.tuning_presets.len().saturating_sub(1));
+
+        self.retune();
+    }
+
+    pub fn find_tuning(&self, name: &str) -> Option<usize> {
+        self.tuning_presets
+            .iter()
+            .position(|tuning| tuning.name == name)
     }
 
     pub fn change_tuning_bank(&mut self, index: usize) {
+        let Some(tuning) = self.tuning_presets.get(index) else {
+            return;
+        };
+
+        println!("Tuning {index}: {}", tuning.name);
         self.tuning_index = index;
 
-        self.retune_fixed();
+        let glide = self.timbre_presets[self.timbre_index].retune_glide;
+        self.glide_retune((glide as u64 * SAMPLE_RATE as u64 / 1000) as u32);
+    }
+
+    // Steps through the bank, wrapping around at either end
+    pub fn next_tuning_bank(&mut self) {
+        let len = self.tuning_presets.len().max(1);
+
+        self.change_tuning_bank((self.tuning_index + 1) % len);
+    }
+
+    pub fn previous_tuning_bank(&mut self) {
+        let len = self.tuning_presets.len().max(1);
+
+        self.change_tuning_bank((self.tuning_index + len - 1) % len);
+    }
+
+    // Sounding voices follow into the new mode. Fixed mode needs a tuning preset to play from
+    pub fn set_mode(&mut self, mode: Mode) {
+        if mode == Mode::Fixed && self.tuning_presets.is_empty() {
+            println!("No tuning presets to switch to");
+            return;
+        }
+
+        println!("Mode: {mode:?}");
+        self.mode = mode;
+
+        self.retune();
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.set_mode(match self.mode {
+            Mode::Fixed => Mode::Dynamic,
+            Mode::Dynamic => Mode::Fixed,
+        });
+    }
+
+    // Retunes notes of the active tuning slot, sounding voices follow immediately. MTS only
+    // retunes fixed tunings, so a dynamic tuning is first frozen into a live slot
+    pub fn set_note_frequencies(&mut self, notes: &[(u8, f64)]) {
+        if self.mode == Mode::Dynamic || self.tuning_presets.is_empty() {
+            let live = TuningPreset {
+                name: LIVE_TUNING.to_string(),
+                frequencies: self.frequencies(),
+            };
+
+            self.tuning_index = match self.find_tuning(LIVE_TUNING) {
+                Some(index) => {
+                    self.tuning_presets[index] = live;
+                    index
+                }
+                None => {
+                    self.tuning_presets.push(live);
+                    self.tuning_presets.len() - 1
+                }
+            };
+
+            println!("Switching to a fixed tuning for MTS");
+            self.mode = Mode::Fixed;
+        }
+
+        if let Some(tuning) = self.tuning_presets.get_mut(self.tuning_index) {
+            for &(note, freq) in notes {
+                if let Some(slot) = tuning.frequencies.get_mut(note as usize) {
+                    *slot = freq;
+                }
+            }
+        }
+
+        self.retune();
+    }
+
+    // In bend ranges, -1 to 1
+    pub fn pitch_bend(&self) -> f64 {
+        self.pitch_bend
+    }
+
+    // -8192..=8191 like the MIDI pitch wheel
+    pub fn set_pitch_bend(&mut self, value: i16) {
+        self.pitch_bend = value as f64 / 8192.0;
+
+        self.retune();
+    }
+
+    pub fn set_bend_range(&mut self, value: u8) {
+        self.timbre_presets[self.timbre_index].bend_range = value;
+
+        self.retune();
+    }
+
+    pub fn set_bend_mode(&mut self, mode: BendMode) {
+        self.timbre_presets[self.timbre_index].bend_mode = mode;
+
+        self.retune();
+    }
+
+    pub fn set_retune_glide(&mut self, milliseconds: u16) {
+        self.timbre_presets[self.timbre_index].retune_glide = milliseconds;
     }
 
     pub fn set_envelope_length(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].env_length = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.env.set_length(value));
     }
     pub fn set_modulator1_envelope_length(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_env_length = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1_env.set_length(value));
     }
     pub fn set_modulator2_envelope_length(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_env_length = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2_env.set_length(value));
     }
     pub fn set_modulator1_ratio_spectrum(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_ratio_spectrum = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1.set_ratio_spectrum(value));
     }
     pub fn set_modulator1_amount_spectrum(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator1_amount_spectrum = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator1.set_amount_spectrum(value));
     }
     pub fn set_modulator2_ratio_spectrum(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_ratio_spectrum = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2.set_ratio_spectrum(value));
     }
     pub fn set_modulator2_amount_spectrum(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].modulator2_amount_spectrum = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.modulator2.set_amount_spectrum(value));
     }
 
     pub fn set_vibrato_depth(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].vibrato_depth = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.set_vibrato_depth(value));
     }
 
+    pub fn set_velocity_curve(&mut self, curve: VelocityCurve) {
+        self.timbre_presets[self.timbre_index].velocity_curve = curve;
+    }
+
+    pub fn set_velocity_brightness(&mut self, value: u8) {
+        self.timbre_presets[self.timbre_index].velocity_brightness = value;
+    }
+
+    pub fn set_channel_pressure(&mut self, value: u8) {
+        self.channel_pressure = value;
+
+        self.all_voices()
+            .for_each(|voice| voice.set_pressure(value));
+    }
+
+    pub fn set_poly_pressure(&mut self, note: u8, value: u8) {
+        if let Some(voice) = self.voices.get_mut(note as usize) {
+            voice.set_pressure(value);
+        }
+    }
+
+    pub fn set_pressure_target(&mut self, target: PressureTarget) {
+        self.timbre_presets[self.timbre_index].pressure_target = target;
+
+        self.all_voices()
+            .for_each(|voice| voice.set_pressure_target(target));
+    }
+
+    pub fn set_slide_target(&mut self, target: PressureTarget) {
+        self.timbre_presets[self.timbre_index].slide_target = target;
+
+        self.all_voices()
+            .for_each(|voice| voice.set_slide_target(target));
+    }
+
     pub fn set_oscillator_balance(&mut self, value: u8) {
         self.timbre_presets[self.timbre_index].oscillator_balance = value;
 
-        self.voices
-            .iter_mut()
+        self.all_voices()
             .for_each(|voice| voice.set_oscillator_balance(value));
     }
 }
@@ -648,8 +1424,7 @@ impl Iterator for Synth {
     type Item = SF;
     fn next(&mut self) -> Option<Self::Item> {
         let sum: i16 = self
-            .voices
-            .iter_mut()
+            .all_voices()
             .filter(|voice| voice.enabled)
             .fold(0, |sum, sample| sum.saturating_add(sample.output()));
 
@@ -658,3 +1433,79 @@ impl Iterator for Synth {
         Some(sample)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dynamic(base_freq: f64, base_note: u8) -> Synth {
+        Synth::new(
+            [SynthSetting::default(); 8],
+            Vec::new(),
+            base_freq,
+            base_note,
+        )
+    }
+
+    fn fixed(names: &[&str]) -> Synth {
+        let presets = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| TuningPreset {
+                name: name.to_string(),
+                frequencies: [100.0 * (index + 1) as f64; 128],
+            })
+            .collect();
+
+        Synth::new([SynthSetting::default(); 8], presets, 440.0, 69)
+    }
+
+    #[test]
+    fn mts_retunes_the_selected_fixed_tuning() {
+        let mut synth = fixed(&["first", "second"]);
+
+        synth.change_tuning_bank(1);
+        synth.set_note_frequencies(&[(60, 440.0)]);
+
+        assert_eq!(synth.tuning_presets.len(), 2);
+        assert_eq!(synth.frequencies()[60], 440.0);
+        assert_eq!(synth.frequencies()[61], 200.0);
+        assert_eq!(synth.tuning_presets[0].frequencies[60], 100.0);
+    }
+
+    #[test]
+    fn mts_freezes_a_dynamic_tuning_into_a_live_slot() {
+        let mut synth = fixed(&["first"]);
+        synth.mode = Mode::Dynamic;
+        let dynamic = synth.frequencies();
+
+        synth.set_note_frequencies(&[(60, 440.0)]);
+
+        assert_eq!(synth.mode, Mode::Fixed);
+        assert_eq!(synth.find_tuning(LIVE_TUNING), Some(1));
+        assert_eq!(synth.frequencies()[60], 440.0);
+        assert_eq!(synth.frequencies()[61], dynamic[61]);
+
+        // Later messages keep retuning the same slot
+        synth.set_note_frequencies(&[(61, 220.0)]);
+
+        assert_eq!(synth.tuning_presets.len(), 2);
+        assert_eq!(synth.frequencies()[60], 440.0);
+        assert_eq!(synth.frequencies()[61], 220.0);
+    }
+
+    #[test]
+    fn fundamentals_are_tuned_from_the_reference() {
+        let mut synth = dynamic(432.0, 69);
+
+        synth.change_fundamental(57);
+        assert_eq!(synth.last_freq, 432.0);
+        assert_eq!(synth.drift(), 0.0);
+
+        // A fifth down in the Pythagorean table
+        synth.change_fundamental(50);
+        assert_eq!(synth.last_note, 62);
+        assert!((synth.last_freq - 288.0).abs() < 1e-9);
+        assert!(synth.drift().abs() < 1e-9);
+    }
+}
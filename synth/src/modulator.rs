@@ -31,6 +31,10 @@ impl Modulator {
     pub fn amount(self) -> f64 {
         self.amount
     }
+
+    pub fn max_amount(self) -> f64 {
+        127.0 / self.amount_spectrum as f64
+    }
     pub fn set_ratio(&mut self, value: u8, carrier_freq: f64) {
         // TODO fine tune
         self.ratio = value as f64 / self.ratio_spectrum as f64;
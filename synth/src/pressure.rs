@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum PressureTarget {
+    #[default]
+    None,
+    VibratoDepth,
+    Modulator1Amount,
+    Modulator2Amount,
+    OscillatorBalance,
+    Gain,
+}
@@ -0,0 +1,54 @@
+// Each bit of a table index enables one prime, lowest bit first
+pub const PRIMES: [u32; 10] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+
+// Keys above middle C toggle the prime whose simplest interval lies that far up
+const KEYS: [Option<u32>; 12] = [
+    None,
+    Some(17),
+    None,
+    Some(19),
+    Some(5),
+    Some(11),
+    Some(23),
+    Some(3),
+    Some(13),
+    Some(7),
+    Some(29),
+    Some(31),
+];
+
+pub fn bit(prime: u32) -> Option<usize> {
+    PRIMES
+        .iter()
+        .position(|&p| p == prime)
+        .map(|position| 1 << position)
+}
+
+// None when a prime has no table
+pub fn table(primes: &[u32]) -> Option<usize> {
+    primes
+        .iter()
+        .try_fold(0, |table, &prime| Some(table | bit(prime)?))
+}
+
+pub fn primes(table: usize) -> Vec<u32> {
+    PRIMES
+        .iter()
+        .enumerate()
+        .filter(|&(position, _)| table & 1 << position != 0)
+        .map(|(_, &prime)| prime)
+        .collect()
+}
+
+// Every prime up to and including the limit
+pub fn limit(limit: u32) -> usize {
+    PRIMES
+        .iter()
+        .enumerate()
+        .filter(|&(_, &prime)| prime <= limit)
+        .fold(0, |table, (position, _)| table | 1 << position)
+}
+
+pub fn key(semitones: u8) -> Option<u32> {
+    KEYS.get(semitones as usize).copied().flatten()
+}
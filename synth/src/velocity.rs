@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum VelocityCurve {
+    Flat,
+    // Louder the harder the key is struck, also for settings saved before there were curves
+    #[default]
+    Linear,
+    Soft,
+    Hard,
+}
+
+impl VelocityCurve {
+    pub fn apply(self, velocity: u8) -> f64 {
+        let velocity = velocity.min(127) as f64 / 127.0;
+
+        match self {
+            VelocityCurve::Flat => 1.0,
+            VelocityCurve::Linear => velocity,
+            VelocityCurve::Soft => velocity.sqrt(),
+            VelocityCurve::Hard => velocity * velocity,
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::SAMPLE_RATE;
 use crate::envelope::Envelope;
 use crate::modulator::Modulator;
 use crate::oscillator::Oscillator;
+use crate::pressure::PressureTarget;
 
 #[derive(Debug, Clone)]
 pub struct Voice {
@@ -17,6 +18,16 @@ pub struct Voice {
     buffer: Option<i16>,
     vibrato_depth: u8,
     oscillator_balance: f64,
+    velocity: f64,
+    brightness: f64,
+    pressure: f64,
+    pressure_target: PressureTarget,
+    slide: f64,
+    slide_target: PressureTarget,
+    // Per sample frequency ratio towards the glide target
+    glide_ratio: f64,
+    glide_target: f64,
+    glide_samples: u32,
 }
 
 impl Voice {
@@ -36,16 +47,101 @@ impl Voice {
             modulator2_env: Envelope::new(1.0, 1, 1, 127, 1, false),
             vibrato_depth: 5,
             oscillator_balance: 0.5,
+            velocity: 1.0,
+            brightness: 1.0,
+            pressure: 0.0,
+            pressure_target: PressureTarget::None,
+            slide: 0.0,
+            slide_target: PressureTarget::None,
+            glide_ratio: 1.0,
+            glide_target: freq,
+            glide_samples: 0,
         }
     }
 
     pub fn set_freq(&mut self, freq: f64) {
+        self.glide_samples = 0;
+        self.apply_freq(freq);
+    }
+
+    // Moves exponentially towards the frequency, a glide already under way keeps its pace
+    // towards the new target
+    pub fn glide_freq(&mut self, freq: f64, samples: u32) {
+        let samples = samples.max(self.glide_samples);
+        let current = self.oscillator1.freq();
+
+        if samples == 0 || current <= 0.0 || freq <= 0.0 {
+            return self.set_freq(freq);
+        }
+
+        self.glide_ratio = (freq / current).powf(1.0 / samples as f64);
+        self.glide_target = freq;
+        self.glide_samples = samples;
+    }
+
+    fn advance_glide(&mut self) {
+        if self.glide_samples == 0 {
+            return;
+        }
+
+        self.glide_samples -= 1;
+
+        if self.glide_samples == 0 {
+            self.apply_freq(self.glide_target);
+        } else {
+            self.apply_freq(self.oscillator1.freq() * self.glide_ratio);
+        }
+    }
+
+    fn apply_freq(&mut self, freq: f64) {
         self.oscillator1.set_freq(freq);
         self.oscillator2.set_freq(freq);
         self.modulator1.set_freq(freq);
         self.modulator2.set_freq(self.modulator1.oscillator.freq());
     }
 
+    // Brightness scales how far the modulators push the carrier
+    pub fn set_velocity(&mut self, velocity: f64, brightness: f64) {
+        self.velocity = velocity;
+        self.brightness = brightness;
+    }
+
+    pub fn set_pressure(&mut self, value: u8) {
+        self.pressure = value.min(127) as f64 / 127.0;
+    }
+
+    pub fn set_pressure_target(&mut self, target: PressureTarget) {
+        self.pressure_target = target;
+    }
+
+    // MPE slide, CC74 on the member channel
+    pub fn set_slide(&mut self, value: u8) {
+        self.slide = value.min(127) as f64 / 127.0;
+    }
+
+    pub fn set_slide_target(&mut self, target: PressureTarget) {
+        self.slide_target = target;
+    }
+
+    // Pressure and slide push the targeted parameter from its setting towards `max`
+    fn pressed(&self, target: PressureTarget, value: f64, max: f64) -> f64 {
+        let amount = [
+            (self.pressure_target, self.pressure),
+            (self.slide_target, self.slide),
+        ]
+        .iter()
+        .filter(|&&(t, _)| t == target)
+        .map(|&(_, amount)| amount)
+        .sum::<f64>()
+        .min(1.0);
+
+        if amount == 0.0 {
+            value
+        } else {
+            value + (max - value) * amount
+        }
+    }
+
     pub fn set_vibrato(&mut self, freq: f64) {
         self.lfo.set_freq(freq);
     }
@@ -55,14 +151,38 @@ impl Voice {
             return self.buffer.take().unwrap();
         }
 
-        let sample = self.oscillator1.sample() * self.oscillator_balance;
-        let sample = sample + self.oscillator2.sample() * (1.0 - self.oscillator_balance);
-
-        let sample = sample * self.env.volume() as f64;
+        self.advance_glide();
+
+        let balance = self.pressed(
+            PressureTarget::OscillatorBalance,
+            self.oscillator_balance,
+            0.0,
+        );
+        let gain = self.pressed(PressureTarget::Gain, 1.0, 2.0);
+        let vibrato_depth = self.pressed(
+            PressureTarget::VibratoDepth,
+            self.vibrato_depth as f64,
+            127.0,
+        );
+        let modulator1_amount = self.pressed(
+            PressureTarget::Modulator1Amount,
+            self.modulator1.amount(),
+            self.modulator1.max_amount(),
+        );
+        let modulator2_amount = self.pressed(
+            PressureTarget::Modulator2Amount,
+            self.modulator2.amount(),
+            self.modulator2.max_amount(),
+        );
+
+        let sample = self.oscillator1.sample() * balance;
+        let sample = sample + self.oscillator2.sample() * (1.0 - balance);
+
+        let sample = sample * self.env.volume() as f64 * self.velocity * gain;
 
         let vibrato = self.lfo.output();
         let delta = (self.oscillator1.freq()
-            * 2.0_f64.powf((self.vibrato_depth as f64 * self.lfo.freq()) / 1200.0))
+            * 2.0_f64.powf((vibrato_depth * self.lfo.freq()) / 1200.0))
             - self.oscillator1.freq();
         let new_freq = self.oscillator1.freq() + delta * vibrato;
 
@@ -71,9 +191,10 @@ impl Voice {
         let pre_modulation = self.modulator2.output();
 
         let pre_modulation_phase_incr = pre_modulation
-            * self.modulator2.amount()
+            * modulator2_amount
             * (self.modulator1.oscillator.freq() / SAMPLE_RATE as f64)
-            * self.modulator2_env.normalized_volume();
+            * self.modulator2_env.normalized_volume()
+            * self.brightness;
 
         self.modulator1
             .oscillator
@@ -82,9 +203,10 @@ impl Voice {
         let modulation = self.modulator1.output();
 
         let modulator_phase_incr = modulation
-            * self.modulator1.amount()
+            * modulator1_amount
             * (self.oscillator1.freq() / SAMPLE_RATE as f64)
-            * self.modulator1_env.normalized_volume();
+            * self.modulator1_env.normalized_volume()
+            * self.brightness;
 
         self.modulator1_env.adjust_volume();
         self.modulator2_env.adjust_volume();
@@ -1,14338 +0,0 @@
-pub const TABLES: [[f64; 12]; 1024] = [
-	[
-		1.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-	],
-	[
-		1.0/1.0,
-		256.0/243.0,
-		9.0/8.0,
-		32.0/27.0,
-		81.0/64.0,
-		4.0/3.0,
-		729.0/512.0,
-		3.0/2.0,
-		128.0/81.0,
-		27.0/16.0,
-		16.0/9.0,
-		243.0/128.0,
-	],
-	[
-		1.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		625.0/512.0,
-		5.0/4.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		8.0/5.0,
-		1024.0/625.0,
-		0.0/1.0,
-		0.0/1.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		25.0/18.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		0.0/1.0,
-		8.0/7.0,
-		0.0/1.0,
-		0.0/1.0,
-		64.0/49.0,
-		0.0/1.0,
-		49.0/32.0,
-		0.0/1.0,
-		0.0/1.0,
-		7.0/4.0,
-		0.0/1.0,
-	],
-	[
-		1.0/1.0,
-		28.0/27.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		81.0/56.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		27.0/14.0,
-	],
-	[
-		1.0/1.0,
-		256.0/245.0,
-		8.0/7.0,
-		400.0/343.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		343.0/200.0,
-		7.0/4.0,
-		245.0/128.0,
-	],
-	[
-		1.0/1.0,
-		15.0/14.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		128.0/121.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		1331.0/1024.0,
-		11.0/8.0,
-		2048.0/1331.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		33.0/32.0,
-		9.0/8.0,
-		11.0/9.0,
-		27.0/22.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		44.0/27.0,
-		18.0/11.0,
-		11.0/6.0,
-		64.0/33.0,
-	],
-	[
-		1.0/1.0,
-		125.0/121.0,
-		11.0/10.0,
-		64.0/55.0,
-		5.0/4.0,
-		160.0/121.0,
-		11.0/8.0,
-		121.0/80.0,
-		8.0/5.0,
-		55.0/32.0,
-		20.0/11.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		121.0/112.0,
-		8.0/7.0,
-		77.0/64.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		128.0/77.0,
-		7.0/4.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		22.0/21.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		21.0/11.0,
-	],
-	[
-		1.0/1.0,
-		80.0/77.0,
-		8.0/7.0,
-		64.0/55.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		55.0/32.0,
-		7.0/4.0,
-		77.0/40.0,
-	],
-	[
-		1.0/1.0,
-		15.0/14.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		16.0/13.0,
-		169.0/128.0,
-		0.0/1.0,
-		256.0/169.0,
-		13.0/8.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		32.0/27.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		26.0/25.0,
-		0.0/1.0,
-		125.0/104.0,
-		5.0/4.0,
-		13.0/10.0,
-		0.0/1.0,
-		20.0/13.0,
-		8.0/5.0,
-		169.0/100.0,
-		0.0/1.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		196.0/169.0,
-		16.0/13.0,
-		64.0/49.0,
-		91.0/64.0,
-		49.0/32.0,
-		13.0/8.0,
-		169.0/98.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		65.0/56.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		112.0/65.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		128.0/121.0,
-		143.0/128.0,
-		13.0/11.0,
-		16.0/13.0,
-		169.0/128.0,
-		11.0/8.0,
-		256.0/169.0,
-		13.0/8.0,
-		22.0/13.0,
-		256.0/143.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		11.0/9.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		18.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		26.0/25.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		289.0/256.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		512.0/289.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		32.0/27.0,
-		34.0/27.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		27.0/17.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		289.0/256.0,
-		20.0/17.0,
-		5.0/4.0,
-		34.0/25.0,
-		289.0/200.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		289.0/160.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		289.0/224.0,
-		64.0/49.0,
-		49.0/34.0,
-		49.0/32.0,
-		448.0/289.0,
-		28.0/17.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		34.0/25.0,
-		7.0/5.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		15.0/14.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		136.0/121.0,
-		289.0/242.0,
-		22.0/17.0,
-		256.0/187.0,
-		11.0/8.0,
-		187.0/128.0,
-		17.0/11.0,
-		289.0/176.0,
-		121.0/68.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		11.0/9.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		18.0/11.0,
-		11.0/6.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		34.0/25.0,
-		11.0/8.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		28.0/17.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		34.0/25.0,
-		7.0/5.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		289.0/256.0,
-		256.0/221.0,
-		16.0/13.0,
-		17.0/13.0,
-		289.0/208.0,
-		26.0/17.0,
-		13.0/8.0,
-		221.0/128.0,
-		512.0/289.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		32.0/27.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		221.0/200.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		221.0/160.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		289.0/160.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		49.0/34.0,
-		26.0/17.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		136.0/121.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		121.0/68.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		11.0/9.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		18.0/11.0,
-		11.0/6.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		19.0/16.0,
-		0.0/1.0,
-		0.0/1.0,
-		361.0/256.0,
-		0.0/1.0,
-		0.0/1.0,
-		32.0/19.0,
-		0.0/1.0,
-		0.0/1.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		27.0/19.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		36.0/19.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		361.0/320.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		361.0/250.0,
-		38.0/25.0,
-		8.0/5.0,
-		32.0/19.0,
-		361.0/200.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		25.0/18.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		133.0/128.0,
-		8.0/7.0,
-		19.0/16.0,
-		49.0/38.0,
-		19.0/14.0,
-		361.0/256.0,
-		28.0/19.0,
-		76.0/49.0,
-		32.0/19.0,
-		7.0/4.0,
-		256.0/133.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		38.0/27.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		36.0/19.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		15.0/14.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		128.0/121.0,
-		1331.0/1216.0,
-		19.0/16.0,
-		152.0/121.0,
-		484.0/361.0,
-		11.0/8.0,
-		361.0/242.0,
-		121.0/76.0,
-		19.0/11.0,
-		0.0/1.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		11.0/9.0,
-		24.0/19.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		18.0/11.0,
-		11.0/6.0,
-		36.0/19.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		11.0/8.0,
-		38.0/25.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		121.0/112.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		21.0/11.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		256.0/247.0,
-		169.0/152.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		361.0/256.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		304.0/169.0,
-		247.0/128.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		104.0/95.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		361.0/256.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		95.0/52.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		91.0/64.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		128.0/121.0,
-		143.0/128.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		256.0/143.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		11.0/9.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		18.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		323.0/256.0,
-		361.0/272.0,
-		361.0/256.0,
-		544.0/361.0,
-		512.0/323.0,
-		32.0/19.0,
-		34.0/19.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		95.0/68.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		34.0/19.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		49.0/38.0,
-		19.0/14.0,
-		49.0/34.0,
-		28.0/19.0,
-		76.0/49.0,
-		28.0/17.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		256.0/187.0,
-		11.0/8.0,
-		187.0/128.0,
-		17.0/11.0,
-		19.0/11.0,
-		34.0/19.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		11.0/9.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		18.0/11.0,
-		11.0/6.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		11.0/8.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		221.0/152.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		34.0/19.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		95.0/68.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		34.0/19.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		49.0/34.0,
-		19.0/13.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		34.0/19.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		529.0/512.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		23.0/16.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		1024.0/529.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		9.0/8.0,
-		27.0/23.0,
-		23.0/18.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		36.0/23.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		23.0/20.0,
-		625.0/529.0,
-		5.0/4.0,
-		125.0/92.0,
-		23.0/16.0,
-		184.0/125.0,
-		8.0/5.0,
-		529.0/320.0,
-		40.0/23.0,
-		46.0/25.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		49.0/46.0,
-		8.0/7.0,
-		28.0/23.0,
-		161.0/128.0,
-		64.0/49.0,
-		23.0/16.0,
-		49.0/32.0,
-		256.0/161.0,
-		23.0/14.0,
-		7.0/4.0,
-		92.0/49.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		46.0/35.0,
-		7.0/5.0,
-		35.0/23.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		46.0/25.0,
-	],
-	[
-		1.0/1.0,
-		15.0/14.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		529.0/484.0,
-		0.0/1.0,
-		1331.0/1058.0,
-		121.0/92.0,
-		11.0/8.0,
-		184.0/121.0,
-		0.0/1.0,
-		0.0/1.0,
-		968.0/529.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		9.0/8.0,
-		11.0/9.0,
-		23.0/18.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		36.0/23.0,
-		18.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		55.0/46.0,
-		5.0/4.0,
-		115.0/88.0,
-		11.0/8.0,
-		121.0/80.0,
-		8.0/5.0,
-		55.0/32.0,
-		20.0/11.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		22.0/21.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		21.0/11.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		46.0/35.0,
-		7.0/5.0,
-		35.0/23.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		184.0/169.0,
-		26.0/23.0,
-		299.0/256.0,
-		16.0/13.0,
-		169.0/128.0,
-		23.0/16.0,
-		256.0/169.0,
-		13.0/8.0,
-		512.0/299.0,
-		23.0/13.0,
-		169.0/92.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		32.0/27.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		23.0/20.0,
-		125.0/104.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		512.0/299.0,
-		23.0/13.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		16.0/13.0,
-		64.0/49.0,
-		23.0/16.0,
-		49.0/32.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		121.0/92.0,
-		11.0/8.0,
-		184.0/121.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		11.0/9.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		18.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		289.0/256.0,
-		0.0/1.0,
-		368.0/289.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		289.0/184.0,
-		0.0/1.0,
-		512.0/289.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		32.0/27.0,
-		23.0/18.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		36.0/23.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		40.0/23.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		119.0/92.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		184.0/119.0,
-		23.0/14.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		136.0/121.0,
-		289.0/242.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		17.0/11.0,
-		289.0/176.0,
-		121.0/68.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		11.0/9.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		18.0/11.0,
-		11.0/6.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		221.0/184.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		221.0/128.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		32.0/27.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		529.0/512.0,
-		608.0/529.0,
-		19.0/16.0,
-		0.0/1.0,
-		722.0/529.0,
-		23.0/16.0,
-		529.0/361.0,
-		0.0/1.0,
-		32.0/19.0,
-		529.0/304.0,
-		1024.0/529.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		19.0/16.0,
-		23.0/18.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		23.0/16.0,
-		38.0/25.0,
-		8.0/5.0,
-		32.0/19.0,
-		40.0/23.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		49.0/46.0,
-		8.0/7.0,
-		19.0/16.0,
-		49.0/38.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		76.0/49.0,
-		23.0/14.0,
-		7.0/4.0,
-		92.0/49.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		209.0/184.0,
-		19.0/16.0,
-		152.0/121.0,
-		121.0/92.0,
-		11.0/8.0,
-		184.0/121.0,
-		121.0/76.0,
-		19.0/11.0,
-		368.0/209.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		11.0/9.0,
-		23.0/18.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		18.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		11.0/8.0,
-		38.0/25.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		184.0/169.0,
-		26.0/23.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		23.0/13.0,
-		169.0/92.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		323.0/256.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		289.0/184.0,
-		32.0/19.0,
-		34.0/19.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		23.0/18.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		34.0/19.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		49.0/38.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		76.0/49.0,
-		23.0/14.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		17.0/11.0,
-		19.0/11.0,
-		34.0/19.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		32.0/19.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		0.0/1.0,
-		32.0/29.0,
-		1024.0/841.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		841.0/512.0,
-		29.0/16.0,
-		0.0/1.0,
-	],
-	[
-		1.0/1.0,
-		29.0/27.0,
-		9.0/8.0,
-		29.0/24.0,
-		36.0/29.0,
-		4.0/3.0,
-		81.0/58.0,
-		3.0/2.0,
-		29.0/18.0,
-		27.0/16.0,
-		16.0/9.0,
-		54.0/29.0,
-	],
-	[
-		1.0/1.0,
-		125.0/116.0,
-		32.0/29.0,
-		29.0/25.0,
-		5.0/4.0,
-		625.0/464.0,
-		29.0/20.0,
-		928.0/625.0,
-		8.0/5.0,
-		50.0/29.0,
-		29.0/16.0,
-		232.0/125.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		25.0/18.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		58.0/49.0,
-		256.0/203.0,
-		64.0/49.0,
-		1421.0/1024.0,
-		49.0/32.0,
-		203.0/128.0,
-		49.0/29.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		28.0/27.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		29.0/21.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		29.0/25.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		49.0/29.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		15.0/14.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		121.0/116.0,
-		32.0/29.0,
-		841.0/704.0,
-		319.0/256.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		512.0/319.0,
-		841.0/512.0,
-		29.0/16.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		29.0/27.0,
-		9.0/8.0,
-		11.0/9.0,
-		36.0/29.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		29.0/18.0,
-		18.0/11.0,
-		11.0/6.0,
-		64.0/33.0,
-	],
-	[
-		1.0/1.0,
-		58.0/55.0,
-		11.0/10.0,
-		29.0/25.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		8.0/5.0,
-		50.0/29.0,
-		20.0/11.0,
-		55.0/29.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		58.0/49.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		49.0/29.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		22.0/21.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		21.0/11.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		29.0/25.0,
-		5.0/4.0,
-		29.0/22.0,
-		7.0/5.0,
-		44.0/29.0,
-		8.0/5.0,
-		49.0/29.0,
-		7.0/4.0,
-		55.0/29.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		0.0/1.0,
-		29.0/26.0,
-		1024.0/841.0,
-		16.0/13.0,
-		169.0/128.0,
-		0.0/1.0,
-		169.0/116.0,
-		13.0/8.0,
-		841.0/512.0,
-		29.0/16.0,
-		0.0/1.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		26.0/25.0,
-		29.0/26.0,
-		29.0/25.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		20.0/13.0,
-		8.0/5.0,
-		50.0/29.0,
-		29.0/16.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		58.0/49.0,
-		16.0/13.0,
-		64.0/49.0,
-		91.0/64.0,
-		49.0/32.0,
-		13.0/8.0,
-		49.0/29.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		29.0/25.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		49.0/29.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		121.0/116.0,
-		29.0/26.0,
-		13.0/11.0,
-		16.0/13.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		13.0/8.0,
-		22.0/13.0,
-		29.0/16.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		11.0/9.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		18.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		26.0/25.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		289.0/232.0,
-		0.0/1.0,
-		841.0/578.0,
-		0.0/1.0,
-		464.0/289.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		29.0/24.0,
-		34.0/27.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		29.0/18.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		20.0/17.0,
-		5.0/4.0,
-		34.0/25.0,
-		29.0/20.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		256.0/203.0,
-		64.0/49.0,
-		49.0/34.0,
-		49.0/32.0,
-		203.0/128.0,
-		28.0/17.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		34.0/25.0,
-		7.0/5.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		22.0/17.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		17.0/11.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		11.0/9.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		18.0/11.0,
-		11.0/6.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		28.0/17.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		29.0/22.0,
-		7.0/5.0,
-		44.0/29.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		29.0/26.0,
-		34.0/29.0,
-		16.0/13.0,
-		17.0/13.0,
-		289.0/208.0,
-		26.0/17.0,
-		13.0/8.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		29.0/26.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		49.0/34.0,
-		26.0/17.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		29.0/26.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		551.0/512.0,
-		32.0/29.0,
-		19.0/16.0,
-		464.0/361.0,
-		38.0/29.0,
-		361.0/256.0,
-		29.0/19.0,
-		361.0/232.0,
-		32.0/19.0,
-		29.0/16.0,
-		1024.0/551.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		38.0/27.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		36.0/19.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		32.0/29.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		29.0/20.0,
-		29.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		25.0/18.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		19.0/16.0,
-		49.0/38.0,
-		19.0/14.0,
-		361.0/256.0,
-		28.0/19.0,
-		76.0/49.0,
-		32.0/19.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		29.0/21.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		36.0/19.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		121.0/116.0,
-		32.0/29.0,
-		19.0/16.0,
-		152.0/121.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		121.0/76.0,
-		19.0/11.0,
-		29.0/16.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		11.0/9.0,
-		24.0/19.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		18.0/11.0,
-		11.0/6.0,
-		36.0/19.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		247.0/232.0,
-		29.0/26.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		361.0/256.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		29.0/16.0,
-		247.0/128.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		29.0/26.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		29.0/20.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		91.0/64.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		91.0/64.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		121.0/116.0,
-		29.0/26.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		29.0/16.0,
-		121.0/64.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		289.0/232.0,
-		38.0/29.0,
-		323.0/232.0,
-		29.0/19.0,
-		361.0/232.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		29.0/20.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		49.0/38.0,
-		19.0/14.0,
-		49.0/34.0,
-		28.0/19.0,
-		76.0/49.0,
-		28.0/17.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		17.0/11.0,
-		19.0/11.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		19.0/11.0,
-		11.0/6.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		221.0/152.0,
-		19.0/13.0,
-		13.0/8.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		29.0/17.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		91.0/64.0,
-		19.0/13.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		529.0/512.0,
-		32.0/29.0,
-		1024.0/841.0,
-		29.0/23.0,
-		667.0/512.0,
-		23.0/16.0,
-		1024.0/667.0,
-		46.0/29.0,
-		841.0/512.0,
-		29.0/16.0,
-		1024.0/529.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		9.0/8.0,
-		29.0/24.0,
-		23.0/18.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		29.0/18.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		23.0/20.0,
-		29.0/25.0,
-		5.0/4.0,
-		125.0/92.0,
-		23.0/16.0,
-		800.0/529.0,
-		8.0/5.0,
-		50.0/29.0,
-		29.0/16.0,
-		46.0/25.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		28.0/23.0,
-		29.0/23.0,
-		64.0/49.0,
-		23.0/16.0,
-		49.0/32.0,
-		46.0/29.0,
-		23.0/14.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		46.0/35.0,
-		7.0/5.0,
-		35.0/23.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		46.0/25.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		32.0/29.0,
-		638.0/529.0,
-		29.0/23.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		46.0/29.0,
-		841.0/512.0,
-		29.0/16.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		9.0/8.0,
-		11.0/9.0,
-		23.0/18.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		29.0/18.0,
-		18.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		29.0/25.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		8.0/5.0,
-		50.0/29.0,
-		20.0/11.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		29.0/22.0,
-		7.0/5.0,
-		44.0/29.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		184.0/169.0,
-		26.0/23.0,
-		299.0/256.0,
-		16.0/13.0,
-		169.0/128.0,
-		23.0/16.0,
-		169.0/116.0,
-		13.0/8.0,
-		512.0/299.0,
-		23.0/13.0,
-		169.0/92.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		23.0/20.0,
-		29.0/25.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		50.0/29.0,
-		23.0/13.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		16.0/13.0,
-		64.0/49.0,
-		23.0/16.0,
-		49.0/32.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		29.0/23.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		46.0/29.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		29.0/24.0,
-		23.0/18.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		29.0/18.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		29.0/23.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		46.0/29.0,
-		23.0/14.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		29.0/23.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		46.0/29.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		17.0/11.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		29.0/24.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		29.0/17.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		34.0/29.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		29.0/17.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		29.0/17.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		464.0/437.0,
-		32.0/29.0,
-		19.0/16.0,
-		29.0/23.0,
-		38.0/29.0,
-		23.0/16.0,
-		29.0/19.0,
-		46.0/29.0,
-		32.0/19.0,
-		29.0/16.0,
-		437.0/232.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		19.0/16.0,
-		23.0/18.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		23.0/16.0,
-		29.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		19.0/16.0,
-		29.0/23.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		46.0/29.0,
-		23.0/14.0,
-		7.0/4.0,
-		56.0/29.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		32.0/29.0,
-		19.0/16.0,
-		29.0/23.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		46.0/29.0,
-		19.0/11.0,
-		29.0/16.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		184.0/169.0,
-		26.0/23.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		23.0/13.0,
-		169.0/92.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		32.0/19.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		44.0/23.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		29.0/23.0,
-		23.0/17.0,
-		23.0/16.0,
-		29.0/19.0,
-		46.0/29.0,
-		29.0/17.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		29.0/17.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		29.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		29.0/23.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		46.0/29.0,
-		23.0/14.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		29.0/19.0,
-		17.0/11.0,
-		19.0/11.0,
-		29.0/16.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		29.0/17.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		29.0/17.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		32.0/17.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		9.0/8.0,
-		32.0/27.0,
-		31.0/24.0,
-		4.0/3.0,
-		93.0/64.0,
-		3.0/2.0,
-		48.0/31.0,
-		27.0/16.0,
-		16.0/9.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		0.0/1.0,
-		155.0/128.0,
-		5.0/4.0,
-		1024.0/775.0,
-		0.0/1.0,
-		775.0/512.0,
-		8.0/5.0,
-		256.0/155.0,
-		0.0/1.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		25.0/18.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		256.0/217.0,
-		62.0/49.0,
-		64.0/49.0,
-		343.0/248.0,
-		49.0/32.0,
-		49.0/31.0,
-		217.0/128.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		28.0/27.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		93.0/64.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		155.0/128.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		217.0/128.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		15.0/14.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		341.0/256.0,
-		11.0/8.0,
-		512.0/341.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		9.0/8.0,
-		11.0/9.0,
-		31.0/24.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		44.0/27.0,
-		18.0/11.0,
-		11.0/6.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		11.0/10.0,
-		64.0/55.0,
-		5.0/4.0,
-		160.0/121.0,
-		11.0/8.0,
-		121.0/80.0,
-		8.0/5.0,
-		55.0/32.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		77.0/64.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		128.0/77.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		22.0/21.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		21.0/11.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		64.0/55.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		55.0/32.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		961.0/832.0,
-		31.0/26.0,
-		16.0/13.0,
-		169.0/128.0,
-		961.0/676.0,
-		248.0/169.0,
-		13.0/8.0,
-		52.0/31.0,
-		1664.0/961.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		31.0/26.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		26.0/25.0,
-		961.0/832.0,
-		31.0/26.0,
-		5.0/4.0,
-		13.0/10.0,
-		961.0/676.0,
-		20.0/13.0,
-		8.0/5.0,
-		52.0/31.0,
-		1664.0/961.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		31.0/26.0,
-		16.0/13.0,
-		64.0/49.0,
-		91.0/64.0,
-		49.0/32.0,
-		13.0/8.0,
-		52.0/31.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		31.0/26.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		52.0/31.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		143.0/128.0,
-		13.0/11.0,
-		16.0/13.0,
-		169.0/128.0,
-		11.0/8.0,
-		248.0/169.0,
-		13.0/8.0,
-		22.0/13.0,
-		248.0/143.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		11.0/9.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		18.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		26.0/25.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		289.0/248.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		496.0/289.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		32.0/27.0,
-		31.0/24.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		48.0/31.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		20.0/17.0,
-		5.0/4.0,
-		34.0/25.0,
-		289.0/200.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		62.0/49.0,
-		64.0/49.0,
-		49.0/34.0,
-		49.0/32.0,
-		49.0/31.0,
-		28.0/17.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		34.0/25.0,
-		7.0/5.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		289.0/242.0,
-		22.0/17.0,
-		248.0/187.0,
-		11.0/8.0,
-		187.0/128.0,
-		17.0/11.0,
-		289.0/176.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		11.0/9.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		18.0/11.0,
-		11.0/6.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		34.0/25.0,
-		11.0/8.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		64.0/49.0,
-		11.0/8.0,
-		49.0/32.0,
-		11.0/7.0,
-		28.0/17.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		31.0/26.0,
-		16.0/13.0,
-		17.0/13.0,
-		289.0/208.0,
-		26.0/17.0,
-		13.0/8.0,
-		52.0/31.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		31.0/26.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		221.0/160.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		31.0/17.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		49.0/34.0,
-		26.0/17.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		589.0/512.0,
-		19.0/16.0,
-		38.0/31.0,
-		961.0/722.0,
-		361.0/256.0,
-		1444.0/961.0,
-		31.0/19.0,
-		32.0/19.0,
-		1024.0/589.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		38.0/27.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		361.0/320.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		361.0/256.0,
-		38.0/25.0,
-		8.0/5.0,
-		32.0/19.0,
-		361.0/200.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		25.0/18.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		19.0/16.0,
-		38.0/31.0,
-		19.0/14.0,
-		217.0/152.0,
-		28.0/19.0,
-		31.0/19.0,
-		32.0/19.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		38.0/27.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		341.0/304.0,
-		19.0/16.0,
-		38.0/31.0,
-		341.0/256.0,
-		11.0/8.0,
-		361.0/242.0,
-		31.0/19.0,
-		19.0/11.0,
-		608.0/341.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		11.0/9.0,
-		24.0/19.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		18.0/11.0,
-		11.0/6.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		11.0/8.0,
-		38.0/25.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		169.0/152.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		361.0/256.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		304.0/169.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		104.0/95.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		361.0/256.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		95.0/52.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		93.0/64.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		91.0/64.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		91.0/64.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		143.0/128.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		248.0/143.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		38.0/31.0,
-		323.0/248.0,
-		361.0/256.0,
-		496.0/323.0,
-		31.0/19.0,
-		32.0/19.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		95.0/68.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		31.0/17.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		38.0/31.0,
-		19.0/14.0,
-		49.0/34.0,
-		28.0/19.0,
-		31.0/19.0,
-		28.0/17.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		248.0/187.0,
-		11.0/8.0,
-		187.0/128.0,
-		17.0/11.0,
-		19.0/11.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		19.0/11.0,
-		11.0/6.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		85.0/64.0,
-		11.0/8.0,
-		95.0/64.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		221.0/152.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		32.0/19.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		361.0/256.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		31.0/17.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		91.0/64.0,
-		19.0/13.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		1058.0/961.0,
-		0.0/1.0,
-		0.0/1.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		0.0/1.0,
-		0.0/1.0,
-		961.0/529.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		9.0/8.0,
-		32.0/27.0,
-		23.0/18.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		36.0/23.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		23.0/20.0,
-		155.0/128.0,
-		5.0/4.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		8.0/5.0,
-		155.0/92.0,
-		40.0/23.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		28.0/23.0,
-		62.0/49.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		49.0/31.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		31.0/23.0,
-		7.0/5.0,
-		35.0/23.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		1058.0/961.0,
-		0.0/1.0,
-		682.0/529.0,
-		31.0/23.0,
-		11.0/8.0,
-		46.0/31.0,
-		0.0/1.0,
-		0.0/1.0,
-		961.0/529.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		9.0/8.0,
-		11.0/9.0,
-		23.0/18.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		36.0/23.0,
-		18.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		55.0/46.0,
-		5.0/4.0,
-		31.0/23.0,
-		11.0/8.0,
-		46.0/31.0,
-		8.0/5.0,
-		55.0/32.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		14.0/11.0,
-		31.0/23.0,
-		11.0/8.0,
-		46.0/31.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		31.0/23.0,
-		7.0/5.0,
-		46.0/31.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		26.0/23.0,
-		31.0/26.0,
-		16.0/13.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		13.0/8.0,
-		52.0/31.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		31.0/26.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		23.0/20.0,
-		31.0/26.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		52.0/31.0,
-		23.0/13.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		16.0/13.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		31.0/23.0,
-		11.0/8.0,
-		46.0/31.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		31.0/23.0,
-		11.0/8.0,
-		46.0/31.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		289.0/248.0,
-		368.0/289.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		289.0/184.0,
-		496.0/289.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		32.0/27.0,
-		23.0/18.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		36.0/23.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		62.0/49.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		49.0/31.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		31.0/24.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		48.0/31.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		34.0/31.0,
-		289.0/242.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		17.0/11.0,
-		289.0/176.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		0.0/1.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		0.0/1.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		31.0/26.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		52.0/31.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		31.0/26.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		52.0/31.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		496.0/437.0,
-		19.0/16.0,
-		38.0/31.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		31.0/19.0,
-		32.0/19.0,
-		437.0/248.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		19.0/16.0,
-		23.0/18.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		23.0/16.0,
-		38.0/25.0,
-		8.0/5.0,
-		32.0/19.0,
-		40.0/23.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		8.0/7.0,
-		19.0/16.0,
-		38.0/31.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		31.0/19.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		209.0/184.0,
-		19.0/16.0,
-		38.0/31.0,
-		31.0/23.0,
-		11.0/8.0,
-		46.0/31.0,
-		31.0/19.0,
-		19.0/11.0,
-		368.0/209.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		31.0/23.0,
-		11.0/8.0,
-		46.0/31.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		32.0/31.0,
-		26.0/23.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		32.0/19.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		38.0/31.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		31.0/19.0,
-		32.0/19.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		32.0/19.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		31.0/17.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		38.0/31.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		31.0/19.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		17.0/11.0,
-		19.0/11.0,
-		31.0/17.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		32.0/19.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		32.0/29.0,
-		992.0/841.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		0.0/1.0,
-		841.0/512.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		29.0/27.0,
-		9.0/8.0,
-		29.0/24.0,
-		31.0/24.0,
-		4.0/3.0,
-		87.0/62.0,
-		3.0/2.0,
-		29.0/18.0,
-		27.0/16.0,
-		16.0/9.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		32.0/29.0,
-		29.0/25.0,
-		5.0/4.0,
-		155.0/116.0,
-		29.0/20.0,
-		232.0/155.0,
-		8.0/5.0,
-		50.0/29.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		25.0/18.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		9.0/5.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		58.0/49.0,
-		62.0/49.0,
-		64.0/49.0,
-		343.0/248.0,
-		49.0/32.0,
-		49.0/31.0,
-		49.0/29.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		28.0/27.0,
-		8.0/7.0,
-		7.0/6.0,
-		9.0/7.0,
-		4.0/3.0,
-		29.0/21.0,
-		3.0/2.0,
-		14.0/9.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		29.0/25.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		49.0/29.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		32.0/29.0,
-		841.0/704.0,
-		319.0/256.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		496.0/319.0,
-		841.0/512.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		29.0/27.0,
-		9.0/8.0,
-		11.0/9.0,
-		31.0/24.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		29.0/18.0,
-		18.0/11.0,
-		11.0/6.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		11.0/10.0,
-		29.0/25.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		8.0/5.0,
-		50.0/29.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		58.0/49.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		49.0/29.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		64.0/55.0,
-		5.0/4.0,
-		29.0/22.0,
-		7.0/5.0,
-		44.0/29.0,
-		8.0/5.0,
-		55.0/32.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		29.0/26.0,
-		31.0/26.0,
-		16.0/13.0,
-		169.0/128.0,
-		961.0/676.0,
-		169.0/116.0,
-		13.0/8.0,
-		52.0/31.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		13.0/9.0,
-		3.0/2.0,
-		13.0/8.0,
-		27.0/16.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		26.0/25.0,
-		29.0/26.0,
-		29.0/25.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		20.0/13.0,
-		8.0/5.0,
-		50.0/29.0,
-		29.0/16.0,
-		25.0/13.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		29.0/20.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		31.0/26.0,
-		16.0/13.0,
-		64.0/49.0,
-		91.0/64.0,
-		49.0/32.0,
-		13.0/8.0,
-		49.0/29.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		91.0/64.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		31.0/26.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		52.0/31.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		29.0/26.0,
-		13.0/11.0,
-		16.0/13.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		13.0/8.0,
-		22.0/13.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		289.0/232.0,
-		0.0/1.0,
-		841.0/578.0,
-		0.0/1.0,
-		464.0/289.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		29.0/24.0,
-		31.0/24.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		29.0/18.0,
-		27.0/16.0,
-		16.0/9.0,
-		17.0/9.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		20.0/17.0,
-		5.0/4.0,
-		34.0/25.0,
-		29.0/20.0,
-		25.0/17.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		62.0/49.0,
-		64.0/49.0,
-		49.0/34.0,
-		49.0/32.0,
-		49.0/31.0,
-		28.0/17.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		31.0/24.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		48.0/31.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		64.0/49.0,
-		7.0/5.0,
-		49.0/32.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		22.0/17.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		17.0/11.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		29.0/24.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		29.0/17.0,
-		11.0/6.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		28.0/17.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		29.0/22.0,
-		7.0/5.0,
-		44.0/29.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		29.0/26.0,
-		31.0/26.0,
-		16.0/13.0,
-		17.0/13.0,
-		289.0/208.0,
-		26.0/17.0,
-		13.0/8.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		29.0/17.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		29.0/26.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		91.0/64.0,
-		26.0/17.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		29.0/26.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		32.0/29.0,
-		19.0/16.0,
-		38.0/31.0,
-		38.0/29.0,
-		361.0/256.0,
-		29.0/19.0,
-		31.0/19.0,
-		32.0/19.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		19.0/18.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		38.0/27.0,
-		3.0/2.0,
-		19.0/12.0,
-		27.0/16.0,
-		16.0/9.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		32.0/29.0,
-		19.0/16.0,
-		5.0/4.0,
-		25.0/19.0,
-		29.0/20.0,
-		29.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		29.0/20.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		19.0/16.0,
-		38.0/31.0,
-		19.0/14.0,
-		217.0/152.0,
-		28.0/19.0,
-		31.0/19.0,
-		32.0/19.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		93.0/64.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		32.0/29.0,
-		19.0/16.0,
-		38.0/31.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		31.0/19.0,
-		19.0/11.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		19.0/11.0,
-		11.0/6.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		29.0/26.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		361.0/256.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		93.0/64.0,
-		3.0/2.0,
-		13.0/8.0,
-		32.0/19.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		29.0/26.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		29.0/20.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		91.0/64.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		91.0/64.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		29.0/26.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		38.0/31.0,
-		38.0/29.0,
-		323.0/232.0,
-		29.0/19.0,
-		31.0/19.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		29.0/17.0,
-		16.0/9.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		38.0/29.0,
-		29.0/20.0,
-		29.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		38.0/31.0,
-		19.0/14.0,
-		361.0/256.0,
-		28.0/19.0,
-		31.0/19.0,
-		28.0/17.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		17.0/11.0,
-		19.0/11.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		19.0/11.0,
-		11.0/6.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		361.0/256.0,
-		19.0/13.0,
-		13.0/8.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		29.0/17.0,
-		16.0/9.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		29.0/20.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		91.0/64.0,
-		19.0/13.0,
-		13.0/8.0,
-		28.0/17.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		24.0/13.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		32.0/29.0,
-		841.0/713.0,
-		29.0/23.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		46.0/29.0,
-		841.0/512.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		9.0/8.0,
-		29.0/24.0,
-		23.0/18.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		29.0/18.0,
-		27.0/16.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		25.0/23.0,
-		23.0/20.0,
-		29.0/25.0,
-		5.0/4.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		8.0/5.0,
-		50.0/29.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		28.0/23.0,
-		29.0/23.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		46.0/29.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		8.0/7.0,
-		7.0/6.0,
-		29.0/23.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		46.0/29.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		31.0/23.0,
-		7.0/5.0,
-		46.0/31.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		32.0/29.0,
-		638.0/529.0,
-		29.0/23.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		46.0/29.0,
-		841.0/512.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		9.0/8.0,
-		29.0/24.0,
-		29.0/23.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		46.0/29.0,
-		48.0/29.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		64.0/55.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		8.0/5.0,
-		55.0/32.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		29.0/22.0,
-		7.0/5.0,
-		44.0/29.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		26.0/23.0,
-		31.0/26.0,
-		16.0/13.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		13.0/8.0,
-		52.0/31.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		48.0/29.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		23.0/20.0,
-		31.0/26.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		52.0/31.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		16.0/13.0,
-		31.0/23.0,
-		23.0/16.0,
-		46.0/31.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		28.0/23.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		29.0/22.0,
-		11.0/8.0,
-		44.0/29.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		29.0/23.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		46.0/29.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		29.0/24.0,
-		29.0/23.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		46.0/29.0,
-		29.0/17.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		29.0/23.0,
-		23.0/17.0,
-		23.0/16.0,
-		34.0/23.0,
-		46.0/29.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		29.0/23.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		46.0/29.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		32.0/29.0,
-		34.0/29.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		17.0/11.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		29.0/24.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		29.0/17.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		20.0/17.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		23.0/17.0,
-		11.0/8.0,
-		34.0/23.0,
-		11.0/7.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		23.0/17.0,
-		7.0/5.0,
-		34.0/23.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		31.0/26.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		29.0/17.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		29.0/24.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		29.0/17.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		23.0/20.0,
-		20.0/17.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		26.0/17.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		13.0/8.0,
-		22.0/13.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		22.0/13.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		26.0/17.0,
-		11.0/7.0,
-		22.0/13.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		20.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		32.0/29.0,
-		19.0/16.0,
-		29.0/23.0,
-		31.0/23.0,
-		23.0/16.0,
-		29.0/19.0,
-		31.0/19.0,
-		32.0/19.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		19.0/12.0,
-		32.0/19.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		31.0/23.0,
-		23.0/16.0,
-		29.0/19.0,
-		8.0/5.0,
-		32.0/19.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		29.0/28.0,
-		8.0/7.0,
-		19.0/16.0,
-		29.0/23.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		31.0/19.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		24.0/23.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		32.0/29.0,
-		19.0/16.0,
-		29.0/23.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		31.0/19.0,
-		19.0/11.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		19.0/12.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		29.0/22.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		19.0/16.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		31.0/29.0,
-		26.0/23.0,
-		19.0/16.0,
-		16.0/13.0,
-		26.0/19.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		32.0/19.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		32.0/19.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		23.0/20.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		32.0/19.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		16.0/13.0,
-		19.0/14.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		23.0/16.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		23.0/22.0,
-		26.0/23.0,
-		13.0/11.0,
-		16.0/13.0,
-		26.0/19.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		20.0/19.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		29.0/23.0,
-		23.0/17.0,
-		23.0/16.0,
-		29.0/19.0,
-		31.0/19.0,
-		29.0/17.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		29.0/17.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		23.0/16.0,
-		29.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		29.0/16.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		29.0/23.0,
-		19.0/14.0,
-		23.0/16.0,
-		28.0/19.0,
-		31.0/19.0,
-		23.0/14.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		24.0/19.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		19.0/12.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		22.0/17.0,
-		23.0/17.0,
-		11.0/8.0,
-		29.0/19.0,
-		17.0/11.0,
-		19.0/11.0,
-		29.0/16.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		9.0/8.0,
-		19.0/16.0,
-		22.0/17.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		17.0/11.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		19.0/16.0,
-		5.0/4.0,
-		23.0/17.0,
-		11.0/8.0,
-		29.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		14.0/11.0,
-		19.0/14.0,
-		11.0/8.0,
-		28.0/19.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		19.0/14.0,
-		7.0/5.0,
-		28.0/19.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		16.0/15.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		29.0/17.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		19.0/16.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		29.0/17.0,
-		16.0/9.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		19.0/16.0,
-		5.0/4.0,
-		13.0/10.0,
-		23.0/16.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		23.0/13.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		16.0/9.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		16.0/13.0,
-		17.0/13.0,
-		23.0/16.0,
-		19.0/13.0,
-		13.0/8.0,
-		23.0/14.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		16.0/13.0,
-		4.0/3.0,
-		17.0/12.0,
-		3.0/2.0,
-		13.0/8.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		17.0/14.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		19.0/17.0,
-		13.0/11.0,
-		16.0/13.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		13.0/8.0,
-		19.0/11.0,
-		23.0/13.0,
-		31.0/16.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		13.0/11.0,
-		16.0/13.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		13.0/8.0,
-		19.0/11.0,
-		11.0/6.0,
-		23.0/12.0,
-	],
-	[
-		1.0/1.0,
-		17.0/16.0,
-		11.0/10.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		11.0/8.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		20.0/11.0,
-		19.0/10.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		9.0/8.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		11.0/6.0,
-		15.0/8.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		14.0/11.0,
-		17.0/13.0,
-		11.0/8.0,
-		19.0/13.0,
-		11.0/7.0,
-		19.0/11.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		7.0/6.0,
-		14.0/11.0,
-		4.0/3.0,
-		11.0/8.0,
-		3.0/2.0,
-		11.0/7.0,
-		12.0/7.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		14.0/13.0,
-		8.0/7.0,
-		13.0/11.0,
-		5.0/4.0,
-		13.0/10.0,
-		7.0/5.0,
-		19.0/13.0,
-		8.0/5.0,
-		17.0/10.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-	[
-		1.0/1.0,
-		13.0/12.0,
-		8.0/7.0,
-		6.0/5.0,
-		5.0/4.0,
-		4.0/3.0,
-		7.0/5.0,
-		3.0/2.0,
-		8.0/5.0,
-		5.0/3.0,
-		7.0/4.0,
-		13.0/7.0,
-	],
-];